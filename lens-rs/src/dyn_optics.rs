@@ -0,0 +1,813 @@
+use crate::path::{OpticPath, PathMiss};
+use crate::*;
+use std::ops::ControlFlow;
+
+/// a type-erased `LensMut` from `S` to `A`
+pub struct DynLens<S: ?Sized, A: ?Sized>(Box<dyn LensObj<S, A>>);
+
+/// a type-erased `LensRef` from `S` to `A`,
+/// for the optics which only read, as `_ref` into a `Rc`
+///
+/// ```
+/// use lens_rs::*;
+/// use std::rc::Rc;
+/// let row = (String::from("Ada"), Rc::new((36, String::from("London"))));
+/// let columns: Vec<DynLensRef<_, String>> = vec![
+///     DynLensRef::new(optics!(_0)),
+///     DynLensRef::new(optics!(_1._ref._1)),
+/// ];
+/// let cells: Vec<&String> = columns.iter().map(|col| row.view_ref(col)).collect();
+/// assert_eq!(cells, vec!["Ada", "London"]);
+/// ```
+pub struct DynLensRef<S: ?Sized, A: ?Sized>(Box<dyn LensRefObj<S, A>>);
+
+/// a type-erased `PrismMut` from `S` to `A`
+pub struct DynPrism<S: ?Sized, A: ?Sized>(Box<dyn PrismObj<S, A>>);
+
+/// a type-erased `PrismRef` from `S` to `A`, for the optics which only read
+pub struct DynPrismRef<S: ?Sized, A: ?Sized>(Box<dyn PrismRefObj<S, A>>);
+
+/// a type-erased `TraversalMut` from `S` to `A`
+pub struct DynTraversal<S: ?Sized, A: ?Sized>(Box<dyn TraversalObj<S, A>>);
+
+/// a type-erased `TraversalRef` from `S` to `A`, for the optics which only read
+pub struct DynTraversalRef<S: ?Sized, A: ?Sized>(Box<dyn TraversalRefObj<S, A>>);
+
+/// a type-erased `Review` building `S` from `A`
+pub struct DynReview<S, A>(Box<dyn ReviewObj<S, A>>);
+
+// the mutable vtables extend the read-only ones
+trait LensRefObj<S: ?Sized, A: ?Sized> {
+    fn view_ref<'a>(&self, source: &'a S) -> &'a A;
+    fn clone_ref_box(&self) -> Box<dyn LensRefObj<S, A>>;
+}
+
+trait LensObj<S: ?Sized, A: ?Sized>: LensRefObj<S, A> {
+    fn view_mut<'a>(&self, source: &'a mut S) -> &'a mut A;
+    fn clone_box(&self) -> Box<dyn LensObj<S, A>>;
+}
+
+trait PrismRefObj<S: ?Sized, A: ?Sized> {
+    fn preview_ref<'a>(&self, source: &'a S) -> Option<&'a A>;
+    fn miss_ref(&self, source: &S, path: &mut OpticPath) -> Option<PathMiss>;
+    fn clone_ref_box(&self) -> Box<dyn PrismRefObj<S, A>>;
+}
+
+trait PrismObj<S: ?Sized, A: ?Sized>: PrismRefObj<S, A> {
+    fn preview_mut<'a>(&self, source: &'a mut S) -> Option<&'a mut A>;
+    fn clone_box(&self) -> Box<dyn PrismObj<S, A>>;
+}
+
+trait TraversalRefObj<S: ?Sized, A: ?Sized> {
+    fn try_for_each_ref<'a>(
+        &self,
+        source: &'a S,
+        f: &mut dyn FnMut(&'a A) -> ControlFlow<()>,
+    ) -> ControlFlow<()>;
+    fn clone_ref_box(&self) -> Box<dyn TraversalRefObj<S, A>>;
+}
+
+trait TraversalObj<S: ?Sized, A: ?Sized>: TraversalRefObj<S, A> {
+    fn try_for_each_mut<'a>(
+        &self,
+        source: &'a mut S,
+        f: &mut dyn FnMut(&'a mut A) -> ControlFlow<()>,
+    ) -> ControlFlow<()>;
+    fn clone_box(&self) -> Box<dyn TraversalObj<S, A>>;
+}
+
+trait ReviewObj<S, A> {
+    fn review(&self, from: A) -> S;
+    fn clone_box(&self) -> Box<dyn ReviewObj<S, A>>;
+}
+
+impl<O, S: ?Sized, A: ?Sized> LensRefObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: LensRef<O, A>,
+{
+    fn view_ref<'a>(&self, source: &'a S) -> &'a A {
+        source.view_ref(self.0.clone())
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn LensRefObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S: ?Sized, A: ?Sized> LensObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: LensMut<O, A>,
+{
+    fn view_mut<'a>(&self, source: &'a mut S) -> &'a mut A {
+        source.view_mut(self.0.clone())
+    }
+
+    fn clone_box(&self) -> Box<dyn LensObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S: ?Sized, A: ?Sized> PrismRefObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: TryPrismRef<O, A>,
+{
+    fn preview_ref<'a>(&self, source: &'a S) -> Option<&'a A> {
+        source.preview_ref(self.0.clone())
+    }
+
+    fn miss_ref(&self, source: &S, path: &mut OpticPath) -> Option<PathMiss> {
+        source.miss_ref(self.0.clone(), path)
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn PrismRefObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S: ?Sized, A: ?Sized> PrismObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: PrismMut<O, A> + TryPrismRef<O, A>,
+{
+    fn preview_mut<'a>(&self, source: &'a mut S) -> Option<&'a mut A> {
+        source.preview_mut(self.0.clone())
+    }
+
+    fn clone_box(&self) -> Box<dyn PrismObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S: ?Sized, A: ?Sized> TraversalRefObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: TraversalRef<O, A>,
+{
+    fn try_for_each_ref<'a>(
+        &self,
+        source: &'a S,
+        f: &mut dyn FnMut(&'a A) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        source.try_for_each_ref(self.0.clone(), f)
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn TraversalRefObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S: ?Sized, A: ?Sized> TraversalObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: TraversalMut<O, A>,
+{
+    fn try_for_each_mut<'a>(
+        &self,
+        source: &'a mut S,
+        f: &mut dyn FnMut(&'a mut A) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        source.try_for_each_mut(self.0.clone(), f)
+    }
+
+    fn clone_box(&self) -> Box<dyn TraversalObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<O, S, A> ReviewObj<S, A> for Erased<O>
+where
+    O: Clone + 'static,
+    S: Review<O, A>,
+{
+    fn review(&self, from: A) -> S {
+        S::review(self.0.clone(), from)
+    }
+
+    fn clone_box(&self) -> Box<dyn ReviewObj<S, A>> {
+        Box::new(self.clone())
+    }
+}
+
+/// a static optic behind a dyn optic
+#[derive(Clone)]
+struct Erased<O>(O);
+
+/// the composition of a dyn optic and the optic after it
+struct Then<D, O>(D, O);
+
+impl<D: Clone, O: Clone> Clone for Then<D, O> {
+    fn clone(&self) -> Self {
+        Then(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> LensRefObj<S, B> for Then<DynLens<S, A>, O>
+where
+    S: 'static,
+    A: LensRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn view_ref<'a>(&self, source: &'a S) -> &'a B {
+        LensRefObj::view_ref(&*self.0 .0, source).view_ref(self.1.clone())
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn LensRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> LensObj<S, B> for Then<DynLens<S, A>, O>
+where
+    S: 'static,
+    A: LensMut<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn view_mut<'a>(&self, source: &'a mut S) -> &'a mut B {
+        LensObj::view_mut(&*self.0 .0, source).view_mut(self.1.clone())
+    }
+
+    fn clone_box(&self) -> Box<dyn LensObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> LensRefObj<S, B> for Then<DynLensRef<S, A>, O>
+where
+    S: 'static,
+    A: LensRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn view_ref<'a>(&self, source: &'a S) -> &'a B {
+        LensRefObj::view_ref(&*self.0 .0, source).view_ref(self.1.clone())
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn LensRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> PrismRefObj<S, B> for Then<DynPrism<S, A>, O>
+where
+    S: 'static,
+    A: TryPrismRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn preview_ref<'a>(&self, source: &'a S) -> Option<&'a B> {
+        PrismRefObj::preview_ref(&*self.0 .0, source)?.preview_ref(self.1.clone())
+    }
+
+    // the segments taken by the erased prism aren't known,
+    // so a miss after it is reported at the path to the source
+    fn miss_ref(&self, source: &S, path: &mut OpticPath) -> Option<PathMiss> {
+        match PrismRefObj::preview_ref(&*self.0 .0, source) {
+            Some(x) => x.miss_ref(self.1.clone(), path),
+            None => PrismRefObj::miss_ref(&*self.0 .0, source, path),
+        }
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn PrismRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> PrismObj<S, B> for Then<DynPrism<S, A>, O>
+where
+    S: 'static,
+    A: PrismMut<O, B> + TryPrismRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn preview_mut<'a>(&self, source: &'a mut S) -> Option<&'a mut B> {
+        PrismObj::preview_mut(&*self.0 .0, source)?.preview_mut(self.1.clone())
+    }
+
+    fn clone_box(&self) -> Box<dyn PrismObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> PrismRefObj<S, B> for Then<DynPrismRef<S, A>, O>
+where
+    S: 'static,
+    A: TryPrismRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn preview_ref<'a>(&self, source: &'a S) -> Option<&'a B> {
+        PrismRefObj::preview_ref(&*self.0 .0, source)?.preview_ref(self.1.clone())
+    }
+
+    // the same as the mutable one
+    fn miss_ref(&self, source: &S, path: &mut OpticPath) -> Option<PathMiss> {
+        match PrismRefObj::preview_ref(&*self.0 .0, source) {
+            Some(x) => x.miss_ref(self.1.clone(), path),
+            None => PrismRefObj::miss_ref(&*self.0 .0, source, path),
+        }
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn PrismRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> TraversalRefObj<S, B> for Then<DynTraversal<S, A>, O>
+where
+    S: 'static,
+    A: TraversalRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn try_for_each_ref<'a>(
+        &self,
+        source: &'a S,
+        f: &mut dyn FnMut(&'a B) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        TraversalRefObj::try_for_each_ref(&*self.0 .0, source, &mut |a: &'a A| {
+            a.try_for_each_ref(self.1.clone(), &mut *f)
+        })
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn TraversalRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> TraversalObj<S, B> for Then<DynTraversal<S, A>, O>
+where
+    S: 'static,
+    A: TraversalMut<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn try_for_each_mut<'a>(
+        &self,
+        source: &'a mut S,
+        f: &mut dyn FnMut(&'a mut B) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        TraversalObj::try_for_each_mut(&*self.0 .0, source, &mut |a: &'a mut A| {
+            a.try_for_each_mut(self.1.clone(), &mut *f)
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn TraversalObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized, B: ?Sized, O> TraversalRefObj<S, B> for Then<DynTraversalRef<S, A>, O>
+where
+    S: 'static,
+    A: TraversalRef<O, B> + 'static,
+    O: Clone + 'static,
+{
+    fn try_for_each_ref<'a>(
+        &self,
+        source: &'a S,
+        f: &mut dyn FnMut(&'a B) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        TraversalRefObj::try_for_each_ref(&*self.0 .0, source, &mut |a: &'a A| {
+            a.try_for_each_ref(self.1.clone(), &mut *f)
+        })
+    }
+
+    fn clone_ref_box(&self) -> Box<dyn TraversalRefObj<S, B>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynLens<S, A> {
+    /// erase the type of a lens
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: LensMut<O, A>,
+    {
+        DynLens(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn lens focusing into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynLens<S, B>
+    where
+        O: Clone + 'static,
+        A: LensMut<O, B>,
+    {
+        DynLens(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynLensRef<S, A> {
+    /// erase the type of a lens which only reads
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: LensRef<O, A>,
+    {
+        DynLensRef(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn lens reading into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynLensRef<S, B>
+    where
+        O: Clone + 'static,
+        A: LensRef<O, B>,
+    {
+        DynLensRef(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynPrism<S, A> {
+    /// erase the type of a prism
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: PrismMut<O, A> + TryPrismRef<O, A>,
+    {
+        DynPrism(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn prism focusing into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynPrism<S, B>
+    where
+        O: Clone + 'static,
+        A: PrismMut<O, B> + TryPrismRef<O, B>,
+    {
+        DynPrism(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynPrismRef<S, A> {
+    /// erase the type of a prism which only reads
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: TryPrismRef<O, A>,
+    {
+        DynPrismRef(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn prism reading into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynPrismRef<S, B>
+    where
+        O: Clone + 'static,
+        A: TryPrismRef<O, B>,
+    {
+        DynPrismRef(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynTraversal<S, A> {
+    /// erase the type of a traversal
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: TraversalMut<O, A>,
+    {
+        DynTraversal(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn traversal focusing into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynTraversal<S, B>
+    where
+        O: Clone + 'static,
+        A: TraversalMut<O, B>,
+    {
+        DynTraversal(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: ?Sized + 'static, A: ?Sized + 'static> DynTraversalRef<S, A> {
+    /// erase the type of a traversal which only reads
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: TraversalRef<O, A>,
+    {
+        DynTraversalRef(Box::new(Erased(optics)))
+    }
+
+    /// compose with a static or dyn traversal reading into `A`
+    pub fn then<O, B: ?Sized + 'static>(self, optics: O) -> DynTraversalRef<S, B>
+    where
+        O: Clone + 'static,
+        A: TraversalRef<O, B>,
+    {
+        DynTraversalRef(Box::new(Then(self, optics)))
+    }
+}
+
+impl<S: 'static, A: 'static> DynReview<S, A> {
+    /// erase the type of a review
+    pub fn new<O>(optics: O) -> Self
+    where
+        O: Clone + 'static,
+        S: Review<O, A>,
+    {
+        DynReview(Box::new(Erased(optics)))
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynLens<S, A> {
+    fn clone(&self) -> Self {
+        DynLens(self.0.clone_box())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynLensRef<S, A> {
+    fn clone(&self) -> Self {
+        DynLensRef(self.0.clone_ref_box())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynPrism<S, A> {
+    fn clone(&self) -> Self {
+        DynPrism(self.0.clone_box())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynPrismRef<S, A> {
+    fn clone(&self) -> Self {
+        DynPrismRef(self.0.clone_ref_box())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynTraversal<S, A> {
+    fn clone(&self) -> Self {
+        DynTraversal(self.0.clone_box())
+    }
+}
+
+impl<S: ?Sized, A: ?Sized> Clone for DynTraversalRef<S, A> {
+    fn clone(&self) -> Self {
+        DynTraversalRef(self.0.clone_ref_box())
+    }
+}
+
+impl<S, A> Clone for DynReview<S, A> {
+    fn clone(&self) -> Self {
+        DynReview(self.0.clone_box())
+    }
+}
+
+mod impl_dyn_lens {
+    use super::*;
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynLens<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: DynLens<S, A>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            f(LensRefObj::view_ref(&*optics.0, self))
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalMut<DynLens<S, A>, A> for S {
+        fn try_for_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: DynLens<S, A>,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            f(LensObj::view_mut(&*optics.0, self))
+        }
+    }
+
+    // the segments taken by the erased optics aren't known
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynLens<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalMut<DynLens<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> PrismRef<DynLens<S, A>, A> for S {
+        fn preview_ref(&self, optics: DynLens<S, A>) -> Option<&A> {
+            Some(LensRefObj::view_ref(&*optics.0, self))
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> PrismMut<DynLens<S, A>, A> for S {
+        fn preview_mut(&mut self, optics: DynLens<S, A>) -> Option<&mut A> {
+            Some(LensObj::view_mut(&*optics.0, self))
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TryPrismRef<DynLens<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> LensRef<DynLens<S, A>, A> for S {
+        fn view_ref(&self, optics: DynLens<S, A>) -> &A {
+            LensRefObj::view_ref(&*optics.0, self)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> LensMut<DynLens<S, A>, A> for S {
+        fn view_mut(&mut self, optics: DynLens<S, A>) -> &mut A {
+            LensObj::view_mut(&*optics.0, self)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynLensRef<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(
+            &'a self,
+            optics: DynLensRef<S, A>,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            f(LensRefObj::view_ref(&*optics.0, self))
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynLensRef<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> PrismRef<DynLensRef<S, A>, A> for S {
+        fn preview_ref(&self, optics: DynLensRef<S, A>) -> Option<&A> {
+            Some(LensRefObj::view_ref(&*optics.0, self))
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TryPrismRef<DynLensRef<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> LensRef<DynLensRef<S, A>, A> for S {
+        fn view_ref(&self, optics: DynLensRef<S, A>) -> &A {
+            LensRefObj::view_ref(&*optics.0, self)
+        }
+    }
+}
+
+mod impl_dyn_prism {
+    use super::*;
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynPrism<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: DynPrism<S, A>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            match PrismRefObj::preview_ref(&*optics.0, self) {
+                Some(x) => f(x),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalMut<DynPrism<S, A>, A> for S {
+        fn try_for_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: DynPrism<S, A>,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            match PrismObj::preview_mut(&*optics.0, self) {
+                Some(x) => f(x),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynPrism<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalMut<DynPrism<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> PrismRef<DynPrism<S, A>, A> for S {
+        fn preview_ref(&self, optics: DynPrism<S, A>) -> Option<&A> {
+            PrismRefObj::preview_ref(&*optics.0, self)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TryPrismRef<DynPrism<S, A>, A> for S {
+        fn miss_ref(&self, optics: DynPrism<S, A>, path: &mut OpticPath) -> Option<PathMiss> {
+            PrismRefObj::miss_ref(&*optics.0, self, path)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> PrismMut<DynPrism<S, A>, A> for S {
+        fn preview_mut(&mut self, optics: DynPrism<S, A>) -> Option<&mut A> {
+            PrismObj::preview_mut(&*optics.0, self)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynPrismRef<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(
+            &'a self,
+            optics: DynPrismRef<S, A>,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            match PrismRefObj::preview_ref(&*optics.0, self) {
+                Some(x) => f(x),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynPrismRef<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> PrismRef<DynPrismRef<S, A>, A> for S {
+        fn preview_ref(&self, optics: DynPrismRef<S, A>) -> Option<&A> {
+            PrismRefObj::preview_ref(&*optics.0, self)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TryPrismRef<DynPrismRef<S, A>, A> for S {
+        fn miss_ref(&self, optics: DynPrismRef<S, A>, path: &mut OpticPath) -> Option<PathMiss> {
+            PrismRefObj::miss_ref(&*optics.0, self, path)
+        }
+    }
+}
+
+mod impl_dyn_traversal {
+    use super::*;
+
+    // run the erased traversal breaking with `()`, keeping the `B` of the caller aside
+    fn try_erased<X, B, F>(
+        run: impl FnOnce(&mut dyn FnMut(X) -> ControlFlow<()>) -> ControlFlow<()>,
+        mut f: F,
+    ) -> ControlFlow<B>
+    where
+        F: FnMut(X) -> ControlFlow<B>,
+    {
+        let mut broken = None;
+        let _ = run(&mut |x| match f(x) {
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+            ControlFlow::Break(b) => {
+                broken = Some(b);
+                ControlFlow::Break(())
+            }
+        });
+        match broken {
+            Some(b) => ControlFlow::Break(b),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynTraversal<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: DynTraversal<S, A>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            try_erased(
+                move |g| TraversalRefObj::try_for_each_ref(&*optics.0, self, g),
+                f,
+            )
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> TraversalMut<DynTraversal<S, A>, A> for S {
+        fn try_for_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: DynTraversal<S, A>,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            try_erased(
+                move |g| TraversalObj::try_for_each_mut(&*optics.0, self, g),
+                f,
+            )
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynTraversal<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalMut<DynTraversal<S, A>, A> for S {}
+
+    impl<S: ?Sized, A: ?Sized> TraversalRef<DynTraversalRef<S, A>, A> for S {
+        fn try_for_each_ref<'a, B, F>(
+            &'a self,
+            optics: DynTraversalRef<S, A>,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a A) -> ControlFlow<B>,
+            A: 'a,
+        {
+            try_erased(
+                move |g| TraversalRefObj::try_for_each_ref(&*optics.0, self, g),
+                f,
+            )
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized> IndexedTraversalRef<DynTraversalRef<S, A>, A> for S {}
+}
+
+mod impl_dyn_review {
+    use super::*;
+
+    impl<S, A> Review<DynReview<S, A>, A> for S {
+        fn review(optics: DynReview<S, A>, from: A) -> Self {
+            ReviewObj::review(&*optics.0, from)
+        }
+    }
+}
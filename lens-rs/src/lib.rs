@@ -56,6 +56,21 @@
 //! x.traverse_mut(optics!(_mapped)).into_iter().for_each(|x| *x += 1);
 //! assert_eq!(x.traverse_ref(optics!(_mapped)), vec![&2, &3, &4]);
 //! assert_eq!(x.view_ref(optics!([1])), &3);
+//! assert_eq!(x.preview_ref(optics!([?5])), None); // `[?i]` never panics
+//! ```
+//!
+//! update by value:
+//!
+//! ```ignore
+//! let x = (1, vec![Some(2), None]);
+//! let x = x.over(optics!(_1._mapped.Some), |i| i * 2).set(optics!(_0), 0);
+//! assert_eq!(x, (0, vec![Some(4), None]));
+//! ```
+//!
+//! `set` clones the value for every focus, while `put` moves it into the single focus of a lens:
+//!
+//! ```ignore
+//! let x = (1, Mutex::new(2)).put(optics!(_1), Mutex::new(3));
 //! ```
 //!
 //! build a structure:
@@ -110,6 +125,20 @@
 //! }
 //! ```
 //!
+//! Derive Iso for single-field structs and for the tuple of all fields:
+//!
+//! ```ignore
+//! #[derive(Iso)]
+//! struct Meters(f64); // optics::_inner converts Meters <-> f64, and is a lens
+//!
+//! #[derive(Iso)]
+//! struct Point { x: f64, y: f64 } // optics::_tuple converts Point <-> (f64, f64)
+//!
+//! assert_eq!(Meters(1.0).to(optics!(_inner)), 1.0);
+//! let p: Point = Iso::from_image(optics!(_tuple), (1.0, 2.0));
+//! let m: Meters = 1.0.to(optics!(_re(optics!(_inner)))); // invert an iso
+//! ```
+//!
 //! Control the mutability:
 //!
 //! ```ignore
@@ -124,6 +153,22 @@
 //! }
 //! ```
 //!
+//! Opt in to resolve the paths at runtime:
+//!
+//! ```ignore
+//! #[derive(Debug, Lens)]
+//! #[optic(path)]
+//! struct Server {
+//!     #[optic]
+//!     port: u16,
+//!     #[optic]
+//!     workers: Vec<(String, Option<u32>)>,
+//! }
+//!
+//! assert_eq!(path::get_as::<u16>(&server, "port"), Ok(&443));
+//! println!("{:?}", path::get_debug(&server, "workers[3]._1.Some"));
+//! ```
+//!
 //! ## A little row polymorphism
 //!
 //! restrict a type has some fields:
@@ -161,6 +206,21 @@
 //! assert_eq!(may_has_c((1, 2, 3)), None);
 //! ```
 //!
+//! `_or` falls back to another optic with the same image when the first one misses,
+//! so that a row-polymorphic helper covers several shapes:
+//!
+//! ```ignore
+//! fn c_or_b<T>(t: &T) -> Option<&i32>
+//! where
+//!     T: PrismRef<Optics![_or(Optics![c], Optics![b])], i32>,
+//! {
+//!     t.preview_ref(optics!(_or(optics!(c), optics!(b))))
+//! }
+//!
+//! assert_eq!(c_or_b(&Foo { a: (), b: 1 }), Some(&1));
+//! assert_eq!(c_or_b(&bar), Some(&0));
+//! ```
+//!
 //! ## Play with structx
 //!
 //! Now, `Lens` has implemented for [`structx`](https://crates.io/crates/structx)
@@ -191,6 +251,96 @@
 //! assert_eq!(s2.preview_ref(optics!(width)), None);
 //! ```
 //!
+//! ## Traverse in parallel
+//!
+//! Enable the feature `rayon` to split the top-level collection of `_mapped` across threads:
+//!
+//! ```ignore
+//! let mut x = vec![Some(1), None, Some(3)];
+//! x.par_for_each_mut(optics!(_mapped.Some), |i| *i += 1);
+//! ```
+//!
+//! ## Reach behind locks
+//!
+//! The optics `_borrow`, `_lock`, `_read` and `_write` go on behind `RefCell`, `Mutex`
+//! and `RwLock`, holding the guard while the closure runs:
+//!
+//! ```ignore
+//! let mut x = (Rc::new(RefCell::new((1, 2))), vec![Arc::new(Mutex::new(Some(3)))]);
+//! assert_eq!(x.with_view_ref(optics!(_0._borrow._1), |i| *i), 2);
+//! x.with_each_mut(optics!(_1._mapped._lock.Some), |i| *i += 1);
+//! ```
+//!
+//! ## Optics for JSON
+//!
+//! Enable the feature `serde_json` to access `serde_json::Value` with `_key`, `_idx`, `_values`
+//! and the prisms `_String`, `_Number`, `_Bool`, `_Array`, `_Object`, `_Null`:
+//!
+//! ```ignore
+//! let mut x = json!({ "name": "lens", "tags": ["optics", "rust"] });
+//! assert_eq!(x.preview_ref(optics!(_key("name")._String)), Some(&"lens".to_string()));
+//! x.for_each_mut(optics!(_key("tags")._values._String), |s| s.push('!'));
+//! ```
+//!
+//! ## Parse and decode
+//!
+//! The codecs `_parsed::<T>` and `_utf8` (with `_hex` and `_base64` behind the features `hex`
+//! and `base64`) go on with the decoded temporary like the locks,
+//! and encode it back after `with_each_mut`.
+//! There is no reference to the decoded value outside the closure,
+//! so they move it out by `preview_owned` and `over_owned` instead of `preview_ref` and `over`,
+//! which go on through the fields, the variants and `_mapped`:
+//!
+//! ```ignore
+//! let mut x = (String::from("8080"), b"lens".to_vec());
+//! x.with_each_mut(optics!(_0._parsed::<u16>), |port| *port += 1);
+//! x.with_each_mut(optics!(_1._utf8), |s| s.push_str("-rs"));
+//! assert_eq!(x, ("8081".to_string(), b"lens-rs".to_vec()));
+//! assert_eq!(x.clone().preview_owned(optics!(_0._parsed::<u16>)), Some(8081));
+//! let x = x.over_owned(optics!(_0._parsed::<u16>), |port| port + 1);
+//! let port: String = Review::review(optics!(_parsed::<u16>), 80u16);
+//! ```
+//!
+//! ## Split the borrows
+//!
+//! `view_mut_many` and `preview_mut_many` give the mutable references of several disjoint paths
+//! made of the derived field and variant optics, and reject the overlapping paths when compiling:
+//!
+//! ```ignore
+//! let mut x = Foo { a: (1, 'a'), b: vec![2] };
+//! let (a, b) = x.view_mut_many((optics!(a._0), optics!(b)));
+//! b.push(*a);
+//! ```
+//!
+//! ## Explain the misses
+//!
+//! `try_preview_ref`, `try_preview_mut` and `try_preview` tell which optic misses and where:
+//!
+//! ```ignore
+//! let x = (1, Ok::<_, ()>((vec![Some(("a", 2)), None], 4)));
+//! let miss = x.try_preview_ref(optics!(_1.Ok._0.[2].Some._1)).unwrap_err();
+//! assert_eq!(miss.depth(), 3);
+//! assert_eq!(miss.to_string(), "index 2 out of bounds at `_1.Ok._0`");
+//! ```
+//!
+//! ## Branch the paths
+//!
+//! The braces in `optics!` and `Optics!` branch a path, `a.{b, c}._0` visits `a.b._0` and `a.c._0`.
+//! The mutable branches should start with disjoint paths of the field and variant optics,
+//! as `view_mut_many` requires,
+//! and the rest optics of every branch, as the optics after the braces, go on from there:
+//!
+//! ```ignore
+//! let mut x = Foo { a: (1, 2), b: (3, 4) };
+//! x.for_each_mut(optics!({a, b}._0), |i| *i *= 10);
+//! assert_eq!(x.sum_of::<i32>(optics!({a, b}.{_0, _1})), 46);
+//!
+//! let mut y = Foo { a: vec![1], b: vec![2] };
+//! y.for_each_mut(optics!({a, b}._mapped), |i| *i += 1);
+//! assert_eq!(y.sum_of::<i32>(optics!({a, b}._mapped)), 5);
+//! // y.for_each_mut(optics!({a._mapped, a._mapped}), ..); // rejected, since `a` overlaps `a`
+//! ```
+//!
 //! # Limitations
 //!
 //! * can't derive `Lens` for enum.
@@ -206,19 +356,41 @@ pub mod optics;
 /// definitions of optics traits
 pub mod traits;
 
-/// definition of `DynLens`, `DynPrism`, `DynTraverse` and `DynReview`.
-// pub mod dyn_optics;
+/// definition of `DynLens`, `DynPrism`, `DynTraversal`, their read-only versions and `DynReview`.
+pub mod dyn_optics;
+
+/// resolve the paths like `"a._1.Some[2]"` at runtime
+pub mod path;
+
+pub use traits::{
+    fold::*, iso::*, lens::*, owned::*, prism::*, review::*, scoped::*, split::*, traversal::*,
+};
 
-pub use traits::{lens::*, prism::*, review::*, traversal::*};
+#[cfg(feature = "rayon")]
+pub use traits::par::*;
+
+pub use dyn_optics::{
+    DynLens, DynLensRef, DynPrism, DynPrismRef, DynReview, DynTraversal, DynTraversalRef,
+};
 
 /// build-in optics
 pub use optics::{
-    _both, _box, _ix, _mapped, _mut, _ref, _0, _1, _10, _11, _12, _13, _14, _15, _16, _2, _3, _4,
-    _5, _6, _7, _8, _9, __,
+    _at, _backwards, _borrow, _both, _box, _branch, _char_strs, _chars, _cow, _deref, _dropping,
+    _element, _entries, _entry, _filtered, _find, _inner, _ix, _keys, _lines, _lock, _mapped, _mut,
+    _nearly, _non, _only, _or, _or_default, _parsed, _prefixed, _range, _re, _read, _ref,
+    _suffixed, _taking, _to, _to_value, _tuple, _utf8, _words, _write, lens_fn, prism_fn, _0, _1,
+    _10, _11, _12, _13, _14, _15, _16, _2, _3, _4, _5, _6, _7, _8, _9, __,
 };
 
+#[cfg(feature = "serde_json")]
+pub use optics::{_Array, _Bool, _Null, _Number, _Object, _String, _idx, _key, _values};
+#[cfg(feature = "base64")]
+pub use optics::_base64;
+#[cfg(feature = "hex")]
+pub use optics::_hex;
+
 /// derive macro
-pub use lens_rs_derive::{Lens, Prism, Review};
+pub use lens_rs_derive::{Iso, Lens, Prism, Review};
 
 /// macro to compose optics
 ///
@@ -226,5 +398,38 @@ pub use lens_rs_derive::{Lens, Prism, Review};
 /// let optics: Optics![a.Some.[0]._0] = optics!(a.Some.[0]._0);
 /// // equivalent to optics!(lens_rs::optics::a.lens_rs::optics::Some.[0].lens_rs::optics::_0)
 /// // the default optics path is `lens_rs::optics`.
+///
+/// let optics: Optics![port._parsed::<u16>] = optics!(port._parsed::<u16>);
+/// // the type arguments go before the rest optics, as `lens_rs::optics::_parsed::<u16, _>(..)`
+///
+/// let optics = optics!(_mapped.a._backwards._taking(3));
+/// // the trailing modifiers modify the foci of the optics before them,
+/// // as `optics!(_taking(3)._backwards._mapped.a)`, to take the last 3 foci
 /// ```
+///
+/// The modifiers lead or end the path:
+///
+/// ```compile_fail
+/// use lens_rs::*;
+/// let optics = optics!(_mapped._taking(1)._mapped);
+/// ```
+///
+/// The modifiers can't go in a branch:
+///
+/// ```compile_fail
+/// use lens_rs::*;
+/// let optics = optics!(_mapped.{_0._taking(1), _1});
+/// ```
+///
+/// `_or` ends the path, and the optics after it go into both of its optics:
+///
+/// ```compile_fail
+/// use lens_rs::*;
+/// let optics = optics!(_or(optics!(_0), optics!(_1)).Some);
+/// // should be `optics!(_or(optics!(_0.Some), optics!(_1.Some)))`
+/// ```
+///
+/// The braces copy the optics after them into every branch,
+/// `optics!(a.{b, c}._mapped)` is `optics!(a.{b._mapped, c._mapped})`,
+/// so it's visited mutably only if `a.b` and `a.c` are disjoint.
 pub use lens_rs_derive::{optics, Optics};
@@ -1,14 +1,47 @@
+pub use impl_codec::{_parsed, _utf8};
+pub use impl_fn::{_to, _to_value, lens_fn, prism_fn};
+pub use impl_lock::{_borrow, _lock, _read, _write};
 pub use lens_rs_generator::generated::*;
+#[cfg(feature = "serde_json")]
+pub use impl_json::{_Array, _Bool, _Null, _Number, _Object, _String, _idx, _key, _values};
+#[cfg(feature = "base64")]
+pub use impl_codec::_base64;
+#[cfg(feature = "hex")]
+pub use impl_codec::_hex;
 
 mod impl4clone_optics {
     use crate::*;
+    use crate::path::{OpticPath, PathMiss};
+    use std::ops::ControlFlow;
     
     impl<Tr: Clone, Source: ?Sized, Image: ?Sized> TraversalRef<&Tr, Image> for Source 
     where
         Source: TraversalRef<Tr, Image>,
     {
-        fn traverse_ref<'a>(&'a self, optics: &Tr) -> Vec<&'a Image> {
-            self.traverse_ref(optics.clone())
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: &Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_for_each_ref(optics.clone(), f)
+        }
+    }
+
+    impl<Tr: Clone, Source: ?Sized, Image: ?Sized> IndexedTraversalRef<&Tr, Image> for Source 
+    where
+        Source: IndexedTraversalRef<Tr, Image>,
+    {
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: &Tr,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_ifor_each_ref(optics.clone(), path, f)
         }
     }
 
@@ -16,8 +49,25 @@ mod impl4clone_optics {
     where
         Source: TraversalMut<Tr, Image>,
     {
-        fn traverse_mut<'a>(&'a mut self, optics: &Tr) -> Vec<&'a mut Image> {
-            self.traverse_mut(optics.clone())
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: &Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_for_each_mut(optics.clone(), f)
+        }
+    }
+
+    impl<Tr: Clone, Source: ?Sized, Image: ?Sized> IndexedTraversalMut<&Tr, Image> for Source 
+    where
+        Source: IndexedTraversalMut<Tr, Image>,
+    {
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: &Tr, path: &mut OpticPath, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_ifor_each_mut(optics.clone(), path, f)
         }
     }
 
@@ -25,8 +75,18 @@ mod impl4clone_optics {
     where
         Source: Traversal<Tr, Image>,
     {
-        fn traverse(self, optics: &Tr) -> Vec<Image> {
-            self.traverse(optics.clone())
+        fn try_for_each_move<B, F>(self, optics: &Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.try_for_each_move(optics.clone(), f)
+        }
+
+        fn over<F>(self, optics: &Tr, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.over(optics.clone(), f)
         }
     }
 
@@ -39,6 +99,15 @@ mod impl4clone_optics {
         }
     }
 
+    impl<Pm: Clone, Source: ?Sized, Image: ?Sized> TryPrismRef<&Pm, Image> for Source 
+    where
+        Source: TryPrismRef<Pm, Image>,
+    {
+        fn miss_ref(&self, optics: &Pm, path: &mut OpticPath) -> Option<PathMiss> {
+            self.miss_ref(optics.clone(), path)
+        }
+    }
+
     impl<Pm: Clone, Source: ?Sized, Image: ?Sized> PrismMut<&Pm, Image> for Source 
     where
         Source: PrismMut<Pm, Image>,
@@ -93,12 +162,43 @@ mod impl4clone_optics {
         }
     }
 
+    impl<Is: Clone, Source, Image> Iso<&Is, Image> for Source
+    where
+        Source: Iso<Is, Image>,
+    {
+        fn to(self, optics: &Is) -> Image {
+            self.to(optics.clone())
+        }
+    }
+
     impl<Tr: Clone, Source: ?Sized, Image: ?Sized> TraversalRef<&mut Tr, Image> for Source 
     where
         Source: TraversalRef<Tr, Image>,
     {
-        fn traverse_ref<'a>(&'a self, optics: &mut Tr) -> Vec<&'a Image> {
-            self.traverse_ref(optics.clone())
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: &mut Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_for_each_ref(optics.clone(), f)
+        }
+    }
+
+    impl<Tr: Clone, Source: ?Sized, Image: ?Sized> IndexedTraversalRef<&mut Tr, Image> for Source 
+    where
+        Source: IndexedTraversalRef<Tr, Image>,
+    {
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: &mut Tr,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_ifor_each_ref(optics.clone(), path, f)
         }
     }
 
@@ -106,8 +206,25 @@ mod impl4clone_optics {
     where
         Source: TraversalMut<Tr, Image>,
     {
-        fn traverse_mut<'a>(&'a mut self, optics: &mut Tr) -> Vec<&'a mut Image> {
-            self.traverse_mut(optics.clone())
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: &mut Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_for_each_mut(optics.clone(), f)
+        }
+    }
+
+    impl<Tr: Clone, Source: ?Sized, Image: ?Sized> IndexedTraversalMut<&mut Tr, Image> for Source 
+    where
+        Source: IndexedTraversalMut<Tr, Image>,
+    {
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: &mut Tr, path: &mut OpticPath, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.try_ifor_each_mut(optics.clone(), path, f)
         }
     }
 
@@ -115,8 +232,18 @@ mod impl4clone_optics {
     where
         Source: Traversal<Tr, Image>,
     {
-        fn traverse(self, optics: &mut Tr) -> Vec<Image> {
-            self.traverse(optics.clone())
+        fn try_for_each_move<B, F>(self, optics: &mut Tr, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.try_for_each_move(optics.clone(), f)
+        }
+
+        fn over<F>(self, optics: &mut Tr, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.over(optics.clone(), f)
         }
     }
 
@@ -129,6 +256,15 @@ mod impl4clone_optics {
         }
     }
 
+    impl<Pm: Clone, Source: ?Sized, Image: ?Sized> TryPrismRef<&mut Pm, Image> for Source 
+    where
+        Source: TryPrismRef<Pm, Image>,
+    {
+        fn miss_ref(&self, optics: &mut Pm, path: &mut OpticPath) -> Option<PathMiss> {
+            self.miss_ref(optics.clone(), path)
+        }
+    }
+
     impl<Pm: Clone, Source: ?Sized, Image: ?Sized> PrismMut<&mut Pm, Image> for Source 
     where
         Source: PrismMut<Pm, Image>,
@@ -182,6 +318,15 @@ mod impl4clone_optics {
             Self::review(optics.clone(), from)
         }
     }
+
+    impl<Is: Clone, Source, Image> Iso<&mut Is, Image> for Source
+    where
+        Source: Iso<Is, Image>,
+    {
+        fn to(self, optics: &mut Is) -> Image {
+            self.to(optics.clone())
+        }
+    }
 }
 
 //impls
@@ -190,6 +335,7 @@ mod impl__ {
      * impl for __
      ************************************************************/
     use crate::*;
+    use std::ops::ControlFlow;
 
     impl<Image, T: From<Image>> Review<__, Image> for T {
         #[inline]
@@ -198,27 +344,79 @@ mod impl__ {
         }
     }
 
+    impl<T> Iso<__, T> for T {
+        #[inline]
+        fn to(self, _optics: __) -> T {
+            self
+        }
+    }
+
     impl<T: ?Sized> TraversalRef<__, T> for T {
         #[inline]
-        fn traverse_ref(&self, _optics: __) -> Vec<&T> {
-            vec![self]
+        fn try_for_each_ref<'a, B, F>(&'a self, _optics: __, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a T) -> ControlFlow<B>,
+            T: 'a,
+        {
+            f(self)
         }
     }
 
+    impl<T: ?Sized> IndexedTraversalRef<__, T> for T {}
+
     impl<T: ?Sized> TraversalMut<__, T> for T {
         #[inline]
-        fn traverse_mut(&mut self, _optics: __) -> Vec<&mut T> {
-            vec![self]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, _optics: __, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut T) -> ControlFlow<B>,
+            T: 'a,
+        {
+            f(self)
         }
     }
 
+    impl<T: ?Sized> IndexedTraversalMut<__, T> for T {}
+
     impl<T> Traversal<__, T> for T {
         #[inline]
-        fn traverse(self, _optics: __) -> Vec<T>
+        fn try_for_each_move<B, F>(self, _optics: __, mut f: F) -> ControlFlow<B>
         where
-            Self: Sized,
+            F: FnMut(T) -> ControlFlow<B>,
+        {
+            f(self)
+        }
+
+        #[inline]
+        fn over<F>(self, _optics: __, mut f: F) -> Self
+        where
+            F: FnMut(T) -> T,
+        {
+            f(self)
+        }
+    }
+
+    impl<T> TraversalOwned<__, T> for T {
+        #[inline]
+        fn try_for_each_owned<B, F>(self, _optics: __, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(T) -> ControlFlow<B>,
+        {
+            f(self)
+        }
+
+        #[inline]
+        fn over_owned<F>(self, _optics: __, mut f: F) -> Self
+        where
+            F: FnMut(T) -> T,
         {
-            vec![self]
+            f(self)
+        }
+    }
+
+    impl<T> PrismOwned<__, T> for T {
+        #[inline]
+        fn preview_owned(self, _optics: __) -> Option<T> {
+            Option::Some(self)
         }
     }
 
@@ -229,6 +427,8 @@ mod impl__ {
         }
     }
 
+    impl<T: ?Sized> TryPrismRef<__, T> for T {}
+
     impl<T: ?Sized> PrismMut<__, T> for T {
         #[inline]
         fn preview_mut(&mut self, _optics: __) -> Option<&mut T> {
@@ -277,6 +477,8 @@ mod impl_tuples {
      * impl for tuple
      ************************************************************/
     use crate::*;
+    use crate::path::{OpticPath, PathMiss, Segment};
+    use std::ops::ControlFlow;
     use lens_rs_derive::derive_lens_for_tuple;
     mod lens_rs {
         pub use crate::*;
@@ -313,6 +515,16 @@ mod impl_tuples {
         }
     }
 
+    impl<Is, Image, A> Iso<_0<Is>, Image> for (A,)
+    where
+        A: Iso<Is, Image>,
+    {
+        #[inline]
+        fn to(self, optics: _0<Is>) -> Image {
+            self.0.to(optics.0)
+        }
+    }
+
     macro_rules! impl_both {
         (<$param:ident> $tuple:ty, $($fields:tt),*) => {
             impl<Tr, Image: ?Sized, $param> TraversalRef<_both<Tr>, Image> for $tuple
@@ -321,10 +533,39 @@ mod impl_tuples {
                 $param: TraversalRef<Tr, Image>,
             {
                 #[inline]
-                fn traverse_ref(&self, optics: _both<Tr>) -> Vec<&Image> {
-                    let mut vec = vec![];
-                    $(vec.append(&mut self.$fields.traverse_ref(optics.0.clone()));)*
-                    vec
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _both<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    $(self.$fields.try_for_each_ref(optics.0.clone(), &mut f)?;)*
+                    ControlFlow::Continue(())
+                }
+            }
+
+            impl<Tr, Image: ?Sized, $param> IndexedTraversalRef<_both<Tr>, Image> for $tuple
+            where
+                Tr: Clone,
+                $param: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _both<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    $(
+                        path.push(Segment::Field(concat!("_", stringify!($fields))));
+                        let flow = self.$fields.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow?;
+                    )*
+                    ControlFlow::Continue(())
                 }
             }
 
@@ -334,10 +575,34 @@ mod impl_tuples {
                 $param: TraversalMut<Tr, Image>,
             {
                 #[inline]
-                fn traverse_mut(&mut self, optics: _both<Tr>) -> Vec<&mut Image> {
-                    let mut vec = vec![];
-                    $(vec.append(&mut self.$fields.traverse_mut(optics.0.clone()));)*
-                    vec
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _both<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    $(self.$fields.try_for_each_mut(optics.0.clone(), &mut f)?;)*
+                    ControlFlow::Continue(())
+                }
+            }
+
+            impl<Tr, Image: ?Sized, $param> IndexedTraversalMut<_both<Tr>, Image> for $tuple
+            where
+                Tr: Clone,
+                $param: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _both<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    $(
+                        path.push(Segment::Field(concat!("_", stringify!($fields))));
+                        let flow = self.$fields.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow?;
+                    )*
+                    ControlFlow::Continue(())
                 }
             }
 
@@ -347,13 +612,20 @@ mod impl_tuples {
                 $param: Traversal<Tr, Image>,
             {
                 #[inline]
-                fn traverse(self, optics: _both<Tr>) -> Vec<Image>
+                fn try_for_each_move<B, F>(self, optics: _both<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    $(self.$fields.try_for_each_move(optics.0.clone(), &mut f)?;)*
+                    ControlFlow::Continue(())
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _both<Tr>, mut f: F) -> Self
                 where
-                    Self: Sized,
+                    F: FnMut(Image) -> Image,
                 {
-                    let mut vec = vec![];
-                    $(vec.append(&mut self.$fields.traverse(optics.0.clone()));)*
-                    vec
+                    ($(self.$fields.over(optics.0.clone(), &mut f),)*)
                 }
             }
         }
@@ -387,6 +659,19 @@ mod impl_tuples {
         }
     }
 
+    impl<Pm: Clone, Image: ?Sized, A> TryPrismRef<_both<Pm>, Image> for (A,)
+    where
+        A: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _both<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            path.push(Segment::Field("_0"));
+            let miss = self.0.miss_ref(optics.0, path);
+            path.pop();
+            miss
+        }
+    }
+
     impl<Pm: Clone, Image: ?Sized, A> PrismMut<_both<Pm>, Image> for (A,)
     where
         A: PrismMut<Pm, Image>,
@@ -454,388 +739,6563 @@ mod impl_tuples {
     }
 }
 
-mod impl_collect {
+mod impl_branch {
     /***********************************************************
-     * impl for iter
+     * impl for _branch
+     * visiting the branches in order, where the mutable ones project their disjoint prefixes
+     * of the field and variant optics first, and go on by the rest optics,
+     * and moving the foci out moves them out of a clone of the source,
+     * but the last branch, which takes the source itself
      ************************************************************/
+    use crate::path::{OpticPath, Segment};
+    use crate::traits::split::Disjoint;
     use crate::*;
-    use std::collections::*;
+    use std::ops::ControlFlow;
 
-    macro_rules! impl_iter {
-        (<$item:ident> $collector:ty) => {
-            impl<Tr: Clone, Image: ?Sized, $item> TraversalRef<_mapped<Tr>, Image> for $collector
-            where
-                $item: TraversalRef<Tr, Image>,
-            {
-                #[inline]
-                fn traverse_ref(&self, optics: _mapped<Tr>) -> Vec<&Image> {
-                    self.into_iter()
-                        .flat_map(|t| t.traverse_ref(optics.0.clone()))
-                        .collect()
-                }
-            }
-
-            impl<Tr: Clone, Image: ?Sized, $item> TraversalMut<_mapped<Tr>, Image> for $collector
-            where
-                $item: TraversalMut<Tr, Image>,
-            {
-                #[inline]
-                fn traverse_mut(&mut self, optics: _mapped<Tr>) -> Vec<&mut Image> {
-                    self.into_iter()
-                        .flat_map(|t| t.traverse_mut(optics.0.clone()))
-                        .collect()
-                }
-            }
+    // a branch projects its longest prefix of the field and variant optics to a place,
+    // and goes on from the place by the rest optics
+    type Prefix<Optic> = <Optic as SplitStaticPath>::Prefix;
+    type Rest<Optic> = <Optic as SplitStaticPath>::Rest;
+    type Place<T, Optic> = <T as ProjectMut<Prefix<Optic>>>::Image;
 
-            impl<Tr: Clone, Image, $item> Traversal<_mapped<Tr>, Image> for $collector
-            where
-                $item: Traversal<Tr, Image>,
-            {
-                #[inline]
-                fn traverse(self, optics: _mapped<Tr>) -> Vec<Image>
-                where
-                    Self: Sized,
-                {
-                    self.into_iter()
-                        .flat_map(|t| t.traverse(optics.0.clone()))
-                        .collect()
-                }
-            }
-        };
+    fn push_segments(path: &mut OpticPath, segments: Segments) {
+        for (name, variant) in segments.iter() {
+            path.push(if variant { Segment::Variant(name) } else { Segment::Field(name) });
+        }
     }
 
-    impl_iter!(<T> Vec<T>);
-    impl_iter!(<T> VecDeque<T>);
-    impl_iter!(<T> LinkedList<T>);
-}
-
-mod impl_ptr {
-    use crate::*;
-    use std::rc::Rc;
-    use std::sync::Arc;
+    fn pop_segments(path: &mut OpticPath, segments: Segments) {
+        segments.iter().for_each(|_| {
+            path.pop();
+        });
+    }
 
-    macro_rules! impl_ref {
-        (<$param:ident> $ptr:ty, $optic:ident) => {
-            impl<$param: ?Sized, Image: ?Sized, Tr> TraversalRef<$optic<Tr>, Image> for $ptr
+    macro_rules! impl_branch {
+        ($($optic:ident $o:ident),*) => {
+            impl<Image: ?Sized, T: ?Sized, $($optic),*> TraversalRef<_branch<($($optic,)*)>, Image> for T
             where
-                $param: TraversalRef<Tr, Image>,
+                $(T: TraversalRef<$optic, Image>,)*
             {
                 #[inline]
-                fn traverse_ref(&self, optics: $optic<Tr>) -> Vec<&Image> {
-                    (**self).traverse_ref(optics.0)
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _branch<($($optic,)*)>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($($o,)*) = optics.0;
+                    $(<T as TraversalRef<$optic, Image>>::try_for_each_ref(self, $o, &mut f)?;)*
+                    ControlFlow::Continue(())
                 }
             }
 
-            impl<$param: ?Sized, Image: ?Sized, Pm> PrismRef<$optic<Pm>, Image> for $ptr
+            impl<Image: ?Sized, T: ?Sized, $($optic),*> IndexedTraversalRef<_branch<($($optic,)*)>, Image> for T
             where
-                $param: PrismRef<Pm, Image>,
+                $(T: IndexedTraversalRef<$optic, Image>,)*
             {
                 #[inline]
-                fn preview_ref(&self, optics: $optic<Pm>) -> Option<&Image> {
-                    (**self).preview_ref(optics.0)
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _branch<($($optic,)*)>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($($o,)*) = optics.0;
+                    $(<T as IndexedTraversalRef<$optic, Image>>::try_ifor_each_ref(self, $o, path, &mut f)?;)*
+                    ControlFlow::Continue(())
                 }
             }
 
-            impl<$param: ?Sized, Image: ?Sized, Ls> LensRef<$optic<Ls>, Image> for $ptr
+            impl<Image: ?Sized, T: ?Sized, $($optic),*> TraversalMut<_branch<($($optic,)*)>, Image> for T
             where
-                $param: LensRef<Ls, Image>,
+                $(
+                    $optic: SplitStaticPath,
+                    // always true, the prefixes are made of the unit-like static optics
+                    Prefix<$optic>: 'static,
+                    T: TraversalRef<$optic, Image> + ProjectMut<Prefix<$optic>>,
+                    Place<T, $optic>: TraversalMut<Rest<$optic>, Image>,
+                )*
             {
                 #[inline]
-                fn view_ref(&self, optics: $optic<Ls>) -> &Image {
-                    (**self).view_ref(optics.0)
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _branch<($($optic,)*)>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let () = <($(Prefix<$optic>,)*) as Disjoint>::DISJOINT;
+                    let this: *mut T = self;
+                    let ($($o,)*) = optics.0;
+                    $(
+                        let (prefix, rest) = $o.split_static_path();
+                        // SAFETY: the disjoint prefixes project to the places never aliasing
+                        if let Some(x) = unsafe { <T as ProjectMut<Prefix<$optic>>>::project_mut(this, prefix) } {
+                            unsafe { &mut *x }.try_for_each_mut(rest, &mut f)?;
+                        }
+                    )*
+                    ControlFlow::Continue(())
                 }
             }
-        };
-    }
 
-    macro_rules! impl_mut {
-        (<$param:ident> $ptr:ty, $optic:ident) => {
-            impl<$param: ?Sized, Image: ?Sized, Tr> TraversalMut<$optic<Tr>, Image> for $ptr
+            impl<Image: ?Sized, T: ?Sized, $($optic),*> IndexedTraversalMut<_branch<($($optic,)*)>, Image> for T
             where
-                $param: TraversalMut<Tr, Image>,
+                $(
+                    $optic: SplitStaticPath,
+                    // always true, the prefixes are made of the unit-like static optics
+                    Prefix<$optic>: 'static,
+                    T: IndexedTraversalRef<$optic, Image> + ProjectMut<Prefix<$optic>>,
+                    Place<T, $optic>: IndexedTraversalMut<Rest<$optic>, Image>,
+                )*
             {
                 #[inline]
-                fn traverse_mut(&mut self, optics: $optic<Tr>) -> Vec<&mut Image> {
-                    (**self).traverse_mut(optics.0)
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _branch<($($optic,)*)>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let () = <($(Prefix<$optic>,)*) as Disjoint>::DISJOINT;
+                    let this: *mut T = self;
+                    let ($($o,)*) = optics.0;
+                    $(
+                        let (prefix, rest) = $o.split_static_path();
+                        // SAFETY: the same as `try_for_each_mut`
+                        if let Some(x) = unsafe { <T as ProjectMut<Prefix<$optic>>>::project_mut(this, prefix) } {
+                            push_segments(path, Prefix::<$optic>::PATH);
+                            let flow = unsafe { &mut *x }.try_ifor_each_mut(rest, path, &mut f);
+                            pop_segments(path, Prefix::<$optic>::PATH);
+                            flow?;
+                        }
+                    )*
+                    ControlFlow::Continue(())
                 }
             }
 
-            impl<$param: ?Sized, Image: ?Sized, Pm> PrismMut<$optic<Pm>, Image> for $ptr
+            impl<Image, T: Clone, $($optic),*> Traversal<_branch<($($optic,)*)>, Image> for T
             where
-                $param: PrismMut<Pm, Image>,
+                $(
+                    $optic: SplitStaticPath,
+                    Prefix<$optic>: 'static,
+                    T: Traversal<$optic, Image> + ProjectMut<Prefix<$optic>>,
+                    Place<T, $optic>: TraversalMut<Rest<$optic>, Image>,
+                )*
             {
                 #[inline]
-                fn preview_mut(&mut self, optics: $optic<Pm>) -> Option<&mut Image> {
-                    (**self).preview_mut(optics.0)
+                fn try_for_each_move<B, F>(self, optics: _branch<($($optic,)*)>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let ($($o,)*) = optics.0;
+                    let mut left = [$(stringify!($o)),*].len();
+                    let mut this = Some(self);
+                    $(
+                        left -= 1;
+                        let source = if left == 0 { this.take() } else { this.clone() };
+                        <T as Traversal<$optic, Image>>::try_for_each_move(source.unwrap(), $o, &mut f)?;
+                    )*
+                    ControlFlow::Continue(())
                 }
-            }
 
-            impl<$param: ?Sized, Image: ?Sized, Ls> LensMut<$optic<Ls>, Image> for $ptr
-            where
-                $param: LensMut<Ls, Image>,
-            {
                 #[inline]
-                fn view_mut(&mut self, optics: $optic<Ls>) -> &mut Image {
-                    (**self).view_mut(optics.0)
+                fn over<F>(self, optics: _branch<($($optic,)*)>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let ($($o,)*) = optics.0;
+                    let this = self;
+                    $(let this = <T as Traversal<$optic, Image>>::over(this, $o, &mut f);)*
+                    this
                 }
             }
         };
     }
 
-    impl<Rv, Image, T> Review<_box<Rv>, Image> for Box<T>
+    impl_branch!(O1 a);
+    impl_branch!(O1 a, O2 b);
+    impl_branch!(O1 a, O2 b, O3 c);
+    impl_branch!(O1 a, O2 b, O3 c, O4 d);
+    impl_branch!(O1 a, O2 b, O3 c, O4 d, O5 e);
+    impl_branch!(O1 a, O2 b, O3 c, O4 d, O5 e, O6 f);
+}
+
+mod impl_or {
+    /***********************************************************
+     * impl for _or
+     * trying the first optic and falling back to the second when it has no focus,
+     * both optics share the image, so `_or` ends the path.
+     * moving the foci out gives no source back when the first optic misses,
+     * so `try_for_each_move` and `preview` test the first one by reference before going in
+     ************************************************************/
+    use crate::path::{OpticPath, PathMiss};
+    use crate::*;
+    use std::ops::ControlFlow;
+
+    impl<P1, P2, Image: ?Sized, T: ?Sized> TraversalRef<_or<P1, P2, __>, Image> for T
     where
-        T: Review<Rv, Image>,
+        T: TraversalRef<P1, Image> + TraversalRef<P2, Image>,
     {
         #[inline]
-        fn review(optics: _box<Rv>, from: Image) -> Self {
-            Box::new(Review::review(optics.0, from))
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _or<P1, P2, __>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _or(_, first, second) = optics;
+            let mut hit = false;
+            <T as TraversalRef<P1, Image>>::try_for_each_ref(self, first, |x| {
+                hit = true;
+                f(x)
+            })?;
+            if hit {
+                ControlFlow::Continue(())
+            } else {
+                <T as TraversalRef<P2, Image>>::try_for_each_ref(self, second, f)
+            }
         }
     }
 
-    impl<Tr, Image, T> Traversal<_box<Tr>, Image> for Box<T>
+    impl<P1, P2, Image: ?Sized, T: ?Sized> IndexedTraversalRef<_or<P1, P2, __>, Image> for T
     where
-        T: Traversal<Tr, Image>,
+        T: IndexedTraversalRef<P1, Image> + IndexedTraversalRef<P2, Image>,
     {
         #[inline]
-        fn traverse(self, optics: _box<Tr>) -> Vec<Image>
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _or<P1, P2, __>,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
         where
-            Self: Sized,
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
         {
-            (*self).traverse(optics.0)
+            let _or(_, first, second) = optics;
+            let mut hit = false;
+            <T as IndexedTraversalRef<P1, Image>>::try_ifor_each_ref(self, first, path, |path, x| {
+                hit = true;
+                f(path, x)
+            })?;
+            if hit {
+                ControlFlow::Continue(())
+            } else {
+                <T as IndexedTraversalRef<P2, Image>>::try_ifor_each_ref(self, second, path, f)
+            }
         }
     }
 
-    impl<Pm, Image, T> Prism<_box<Pm>, Image> for Box<T>
+    impl<P1, P2, Image: ?Sized, T: ?Sized> TraversalMut<_or<P1, P2, __>, Image> for T
     where
-        T: Prism<Pm, Image>,
+        T: TraversalMut<P1, Image> + TraversalMut<P2, Image>,
     {
         #[inline]
-        fn preview(self, optics: _box<Pm>) -> Option<Image>
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _or<P1, P2, __>, mut f: F) -> ControlFlow<B>
         where
-            Self: Sized,
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
         {
-            (*self).preview(optics.0)
+            let _or(_, first, second) = optics;
+            let this: *mut T = self;
+            let mut hit = false;
+            // SAFETY: `this` is borrowed again only if the first optic has no focus,
+            // when no reference from the first borrow is given out
+            unsafe {
+                <T as TraversalMut<P1, Image>>::try_for_each_mut(&mut *this, first, |x| {
+                    hit = true;
+                    f(x)
+                })?;
+                if hit {
+                    ControlFlow::Continue(())
+                } else {
+                    <T as TraversalMut<P2, Image>>::try_for_each_mut(&mut *this, second, f)
+                }
+            }
         }
     }
 
-    impl<Ls, Image, T> Lens<_box<Ls>, Image> for Box<T>
+    impl<P1, P2, Image: ?Sized, T: ?Sized> IndexedTraversalMut<_or<P1, P2, __>, Image> for T
     where
-        T: Lens<Ls, Image>,
+        T: IndexedTraversalMut<P1, Image> + IndexedTraversalMut<P2, Image>,
     {
         #[inline]
-        fn view(self, optics: _box<Ls>) -> Image
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _or<P1, P2, __>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
         where
-            Self: Sized,
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
         {
-            (*self).view(optics.0)
+            let _or(_, first, second) = optics;
+            let this: *mut T = self;
+            let mut hit = false;
+            // SAFETY: the same as `try_for_each_mut`
+            unsafe {
+                <T as IndexedTraversalMut<P1, Image>>::try_ifor_each_mut(&mut *this, first, path, |path, x| {
+                    hit = true;
+                    f(path, x)
+                })?;
+                if hit {
+                    ControlFlow::Continue(())
+                } else {
+                    <T as IndexedTraversalMut<P2, Image>>::try_ifor_each_mut(&mut *this, second, path, f)
+                }
+            }
         }
     }
 
-    impl<Rv, Image, T> Review<_box<Rv>, Image> for Rc<T>
+    impl<P1: Clone, P2, Image, T> Traversal<_or<P1, P2, __>, Image> for T
     where
-        T: Review<Rv, Image>,
+        T: Traversal<P1, Image> + Traversal<P2, Image>,
     {
         #[inline]
-        fn review(optics: _box<Rv>, from: Image) -> Self {
-            Rc::new(Review::review(optics.0, from))
+        fn try_for_each_move<B, F>(self, optics: _or<P1, P2, __>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            let _or(_, first, second) = optics;
+            if Fold::<P1, Image>::has(&self, first.clone()) {
+                <T as Traversal<P1, Image>>::try_for_each_move(self, first, f)
+            } else {
+                <T as Traversal<P2, Image>>::try_for_each_move(self, second, f)
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _or<P1, P2, __>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            let _or(_, first, second) = optics;
+            let mut hit = false;
+            let this = <T as Traversal<P1, Image>>::over(self, first, |x| {
+                hit = true;
+                f(x)
+            });
+            if hit {
+                this
+            } else {
+                <T as Traversal<P2, Image>>::over(this, second, f)
+            }
         }
     }
 
-    impl<Rv, Image, T> Review<_box<Rv>, Image> for Arc<T>
+    impl<P1, P2, Image: ?Sized, T: ?Sized> PrismRef<_or<P1, P2, __>, Image> for T
     where
-        T: Review<Rv, Image>,
+        T: PrismRef<P1, Image> + PrismRef<P2, Image>,
     {
         #[inline]
-        fn review(optics: _box<Rv>, from: Image) -> Self {
-            Arc::new(Review::review(optics.0, from))
+        fn preview_ref(&self, optics: _or<P1, P2, __>) -> Option<&Image> {
+            let _or(_, first, second) = optics;
+            <T as PrismRef<P1, Image>>::preview_ref(self, first)
+                .or_else(|| <T as PrismRef<P2, Image>>::preview_ref(self, second))
         }
     }
 
-    impl_ref!(<T> Box<T>, _ref);
-    impl_ref!(<T> Box<T>, _mut);
-    impl_ref!(<T> Box<T>, _box);
-    impl_ref!(<T> Rc<T>, _ref);
-    impl_ref!(<T> Arc<T>, _ref);
-    impl_ref!(<T> &'_ mut T, _ref);
-    impl_ref!(<T> &'_ mut T, _mut);
-    impl_ref!(<T> &'_ T, _ref);
+    impl<P1, P2, Image: ?Sized, T: ?Sized> TryPrismRef<_or<P1, P2, __>, Image> for T
+    where
+        T: TryPrismRef<P1, Image> + TryPrismRef<P2, Image>,
+    {
+        // the second optic is the last one tried, so its miss is the one reported
+        #[inline]
+        fn miss_ref(&self, optics: _or<P1, P2, __>, path: &mut OpticPath) -> Option<PathMiss> {
+            let _or(_, first, second) = optics;
+            <T as TryPrismRef<P1, Image>>::miss_ref(self, first, path)?;
+            <T as TryPrismRef<P2, Image>>::miss_ref(self, second, path)
+        }
+    }
 
-    impl_mut!(<T> Box<T>, _box);
-    impl_mut!(<T> Box<T>, _mut);
-    impl_mut!(<T> &'_ mut T, _mut);
+    impl<P1, P2, Image: ?Sized, T: ?Sized> PrismMut<_or<P1, P2, __>, Image> for T
+    where
+        T: PrismMut<P1, Image> + PrismMut<P2, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: _or<P1, P2, __>) -> Option<&mut Image> {
+            let _or(_, first, second) = optics;
+            let this: *mut T = self;
+            // SAFETY: `this` is borrowed again only if the first optic misses
+            unsafe {
+                match <T as PrismMut<P1, Image>>::preview_mut(&mut *this, first) {
+                    Some(x) => Some(x),
+                    None => <T as PrismMut<P2, Image>>::preview_mut(&mut *this, second),
+                }
+            }
+        }
+    }
+
+    impl<P1: Clone, P2, Image, T> Prism<_or<P1, P2, __>, Image> for T
+    where
+        T: Prism<P1, Image> + Prism<P2, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _or<P1, P2, __>) -> Option<Image> {
+            let _or(_, first, second) = optics;
+            if Fold::<P1, Image>::has(&self, first.clone()) {
+                <T as Prism<P1, Image>>::preview(self, first)
+            } else {
+                <T as Prism<P2, Image>>::preview(self, second)
+            }
+        }
+    }
+
+    // reviewing through the first optic, which is the one previewed back
+    impl<P1, P2, Image, T> Review<_or<P1, P2, __>, Image> for T
+    where
+        T: Review<P1, Image>,
+    {
+        #[inline]
+        fn review(optics: _or<P1, P2, __>, from: Image) -> Self {
+            T::review(optics.1, from)
+        }
+    }
 }
 
-mod impl_ix {
+mod impl_collect {
+    /***********************************************************
+     * impl for iter
+     * the sets and `BinaryHeap` only implement `TraversalRef` and `TraversalOwned`,
+     * since mutating their items in place would break their invariants,
+     * but the items moved out and rewritten are hashed or ordered again.
+     * every collection forwards `TraversalOwned` to its items
+     ************************************************************/
     use crate::*;
-    use std::borrow::Borrow;
-    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use crate::path::{OpticPath, PathKey, Segment};
+    use std::ops::ControlFlow;
+    use std::collections::*;
     use std::hash::Hash;
-    use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
-
-    macro_rules! impl_ix {
-        (<$($param:ident,)? $(const $c:ident: $ct: ty)?> $t:ty[$ix:ty]: $o:ty) => {
 
-            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalRef<_ix<$ix, Tr>, Image> for $t
+    macro_rules! impl_iter_ref {
+        (<$item:ident $(, const $c:ident: $ct:ty)?> $collector:ty) => {
+            impl<Tr: Clone, Image: ?Sized, $item $(, const $c: $ct)?> TraversalRef<_mapped<Tr>, Image> for $collector
             where
-                $o: TraversalRef<Tr, Image>,
+                $item: TraversalRef<Tr, Image>,
             {
                 #[inline]
-                fn traverse_ref(&self, optics: _ix<$ix, Tr>) -> Vec<&Image> {
-                    self[optics.1].traverse_ref(optics.0)
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter()
+                        .try_for_each(|t| t.try_for_each_ref(optics.0.clone(), &mut f))
                 }
             }
 
-            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalMut<_ix<$ix, Tr>, Image> for $t
+            impl<Tr: Clone, Image: ?Sized, $item $(, const $c: $ct)?> IndexedTraversalRef<_mapped<Tr>, Image> for $collector
             where
-                $o: TraversalMut<Tr, Image>,
+                $item: IndexedTraversalRef<Tr, Image>,
             {
                 #[inline]
-                fn traverse_mut(&mut self, optics: _ix<$ix, Tr>) -> Vec<&mut Image> {
-                    self[optics.1].traverse_mut(optics.0)
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _mapped<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter().enumerate().try_for_each(|(i, t)| {
+                        path.push(Segment::Index(i));
+                        let flow = t.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
                 }
             }
+        };
+    }
 
-            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismRef<_ix<$ix, Pm>, Image> for $t
+    macro_rules! impl_iter_mut {
+        (<$item:ident $(, const $c:ident: $ct:ty)?> $collector:ty) => {
+            impl<Tr: Clone, Image: ?Sized, $item $(, const $c: $ct)?> TraversalMut<_mapped<Tr>, Image> for $collector
             where
-                $o: PrismRef<Pm, Image>,
+                $item: TraversalMut<Tr, Image>,
             {
                 #[inline]
-                fn preview_ref(&self, optics: _ix<$ix, Pm>) -> Option<&Image> {
-                    self[optics.1].preview_ref(optics.0)
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter_mut()
+                        .try_for_each(|t| t.try_for_each_mut(optics.0.clone(), &mut f))
                 }
             }
 
-            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismMut<_ix<$ix, Pm>, Image> for $t
+            impl<Tr: Clone, Image: ?Sized, $item $(, const $c: $ct)?> IndexedTraversalMut<_mapped<Tr>, Image> for $collector
             where
-                $o: PrismMut<Pm, Image>,
+                $item: IndexedTraversalMut<Tr, Image>,
             {
                 #[inline]
-                fn preview_mut(&mut self, optics: _ix<$ix, Pm>) -> Option<&mut Image> {
-                    self[optics.1].preview_mut(optics.0)
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _mapped<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter_mut().enumerate().try_for_each(|(i, t)| {
+                        path.push(Segment::Index(i));
+                        let flow = t.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
                 }
             }
+        };
+    }
 
-            impl<$($param,)? Image: ?Sized, Ls, $(const $c: $ct)?> LensRef<_ix<$ix, Ls>, Image> for $t
+    macro_rules! impl_iter {
+        (<$item:ident> $collector:ty) => {
+            impl_iter_ref!(<$item> $collector);
+            impl_iter_mut!(<$item> $collector);
+
+            impl<Tr: Clone, Image, $item> Traversal<_mapped<Tr>, Image> for $collector
             where
-                $o: LensRef<Ls, Image>,
+                $item: Traversal<Tr, Image>,
             {
                 #[inline]
-                fn view_ref(&self, optics: _ix<$ix, Ls>) -> &Image {
-                    self[optics.1].view_ref(optics.0)
+                fn try_for_each_move<B, F>(self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    self.into_iter()
+                        .try_for_each(|t| t.try_for_each_move(optics.0.clone(), &mut f))
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _mapped<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    self.into_iter()
+                        .map(|t| t.over(optics.0.clone(), &mut f))
+                        .collect()
                 }
             }
+        };
+    }
 
-            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> LensMut<_ix<$ix, Pm>, Image> for $t
+    macro_rules! impl_iter_owned {
+        (<$item:ident> $collector:ty, [$($bound:tt)*]) => {
+            impl<Tr: Clone, Image, $item> TraversalOwned<_mapped<Tr>, Image> for $collector
             where
-                $o: LensMut<Pm, Image>,
+                $($bound)*
+                $item: TraversalOwned<Tr, Image>,
             {
                 #[inline]
-                fn view_mut(&mut self, optics: _ix<$ix, Pm>) -> &mut Image {
-                    self[optics.1].view_mut(optics.0)
+                fn try_for_each_owned<B, F>(self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    self.into_iter()
+                        .try_for_each(|t| t.try_for_each_owned(optics.0.clone(), &mut f))
+                }
+
+                #[inline]
+                fn over_owned<F>(self, optics: _mapped<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    self.into_iter()
+                        .map(|t| t.over_owned(optics.0.clone(), &mut f))
+                        .collect()
                 }
             }
-        }
+        };
     }
 
-    impl_ix!(<T,> Vec<T>[usize]: T);
-    impl_ix!(<T,> Vec<T>[Range<usize>]: [T]);
-    impl_ix!(<T,> Vec<T>[RangeTo<usize>]: [T]);
-    impl_ix!(<T,> Vec<T>[RangeFrom<usize>]: [T]);
-    impl_ix!(<T,> Vec<T>[RangeFull]: [T]);
-
-    impl_ix!(<T,> VecDeque<T>[usize]: T);
-
-    impl_ix!(<T, const N: usize> [T; N][usize]: T);
-    impl_ix!(<T, const N: usize> [T; N][Range<usize>]: [T]);
-    impl_ix!(<T, const N: usize> [T; N][RangeTo<usize>]: [T]);
-    impl_ix!(<T, const N: usize> [T; N][RangeFrom<usize>]: [T]);
-    impl_ix!(<T, const N: usize> [T; N][RangeFull]: [T]);
+    impl_iter!(<T> Vec<T>);
+    impl_iter!(<T> VecDeque<T>);
+    impl_iter!(<T> LinkedList<T>);
 
-    impl_ix!(<T,> [T][usize]: T);
-    impl_ix!(<T,> [T][Range<usize>]: [T]);
-    impl_ix!(<T,> [T][RangeTo<usize>]: [T]);
-    impl_ix!(<T,> [T][RangeFrom<usize>]: [T]);
-    impl_ix!(<T,> [T][RangeFull]: [T]);
+    impl_iter_owned!(<T> Vec<T>, []);
+    impl_iter_owned!(<T> VecDeque<T>, []);
+    impl_iter_owned!(<T> LinkedList<T>, []);
 
-    impl_ix!(<> String[Range<usize>]: str);
-    impl_ix!(<> String[RangeTo<usize>]: str);
-    impl_ix!(<> String[RangeFrom<usize>]: str);
-    impl_ix!(<> String[RangeFull]: str);
+    impl_iter_ref!(<T> [T]);
+    impl_iter_mut!(<T> [T]);
 
-    impl_ix!(<> str[Range<usize>]: str);
-    impl_ix!(<> str[RangeTo<usize>]: str);
-    impl_ix!(<> str[RangeFrom<usize>]: str);
-    impl_ix!(<> str[RangeFull]: str);
+    impl_iter_ref!(<T, const N: usize> [T; N]);
+    impl_iter_mut!(<T, const N: usize> [T; N]);
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalRef<_ix<&'_ Q, Tr>, Image> for BTreeMap<K, V>
+    impl<Tr: Clone, Image, T, const N: usize> Traversal<_mapped<Tr>, Image> for [T; N]
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord,
-        V: TraversalRef<Tr, Image>,
+        T: Traversal<Tr, Image>,
     {
         #[inline]
-        fn traverse_ref(&self, optics: _ix<&Q, Tr>) -> Vec<&Image> {
-            self[optics.1].traverse_ref(optics.0)
+        fn try_for_each_move<B, F>(self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            IntoIterator::into_iter(self).try_for_each(|t| t.try_for_each_move(optics.0.clone(), &mut f))
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _mapped<Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.map(|t| t.over(optics.0.clone(), &mut f))
         }
     }
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalRef<_ix<&'_ Q, Tr>, Image> for HashMap<K, V>
+    impl<Tr: Clone, Image, T, const N: usize> TraversalOwned<_mapped<Tr>, Image> for [T; N]
     where
-        K: Eq + Hash + Borrow<Q>,
-        Q: Eq + Hash,
-        V: TraversalRef<Tr, Image>,
+        T: TraversalOwned<Tr, Image>,
     {
         #[inline]
-        fn traverse_ref(&self, optics: _ix<&Q, Tr>) -> Vec<&Image> {
-            self[optics.1].traverse_ref(optics.0)
+        fn try_for_each_owned<B, F>(self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            IntoIterator::into_iter(self).try_for_each(|t| t.try_for_each_owned(optics.0.clone(), &mut f))
+        }
+
+        #[inline]
+        fn over_owned<F>(self, optics: _mapped<Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.map(|t| t.over_owned(optics.0.clone(), &mut f))
         }
     }
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismRef<_ix<&'_ Q, Pm>, Image> for BTreeMap<K, V>
+    impl_iter_ref!(<T> Box<[T]>);
+    impl_iter_mut!(<T> Box<[T]>);
+
+    impl<Tr: Clone, Image, T> Traversal<_mapped<Tr>, Image> for Box<[T]>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord,
-        V: PrismRef<Pm, Image>,
+        T: Traversal<Tr, Image>,
     {
         #[inline]
-        fn preview_ref(&self, optics: _ix<&Q, Pm>) -> Option<&Image> {
-            self[optics.1].preview_ref(optics.0)
+        fn try_for_each_move<B, F>(self, optics: _mapped<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.into_vec().try_for_each_move(optics, f)
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _mapped<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.into_vec().over(optics, f).into_boxed_slice()
         }
     }
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismRef<_ix<&'_ Q, Pm>, Image> for HashMap<K, V>
+    impl<Tr: Clone, Image, T> TraversalOwned<_mapped<Tr>, Image> for Box<[T]>
     where
-        K: Eq + Hash + Borrow<Q>,
-        Q: Eq + Hash,
-        V: PrismRef<Pm, Image>,
+        T: TraversalOwned<Tr, Image>,
     {
         #[inline]
-        fn preview_ref(&self, optics: _ix<&Q, Pm>) -> Option<&Image> {
-            self[optics.1].preview_ref(optics.0)
+        fn try_for_each_owned<B, F>(self, optics: _mapped<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.into_vec().try_for_each_owned(optics, f)
+        }
+
+        #[inline]
+        fn over_owned<F>(self, optics: _mapped<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.into_vec().over_owned(optics, f).into_boxed_slice()
         }
     }
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Ls> LensRef<_ix<&'_ Q, Ls>, Image> for BTreeMap<K, V>
+    impl_iter_ref!(<T> Option<T>);
+    impl_iter_mut!(<T> Option<T>);
+
+    impl<Tr: Clone, Image, T> Traversal<_mapped<Tr>, Image> for Option<T>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord,
-        V: LensRef<Ls, Image>,
+        T: Traversal<Tr, Image>,
     {
         #[inline]
-        fn view_ref(&self, optics: _ix<&Q, Ls>) -> &Image {
-            self[optics.1].view_ref(optics.0)
+        fn try_for_each_move<B, F>(self, optics: _mapped<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match self {
+                Some(t) => t.try_for_each_move(optics.0, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _mapped<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.map(|t| t.over(optics.0, f))
         }
     }
 
-    impl<K, Q: ?Sized, V, Image: ?Sized, Ls> LensRef<_ix<&'_ Q, Ls>, Image> for HashMap<K, V>
+    impl<Tr: Clone, Image, T> TraversalOwned<_mapped<Tr>, Image> for Option<T>
     where
-        K: Eq + Hash + Borrow<Q>,
-        Q: Eq + Hash,
-        V: LensRef<Ls, Image>,
+        T: TraversalOwned<Tr, Image>,
     {
         #[inline]
-        fn view_ref(&self, optics: _ix<&Q, Ls>) -> &Image {
-            self[optics.1].view_ref(optics.0)
+        fn try_for_each_owned<B, F>(self, optics: _mapped<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match self {
+                Some(t) => t.try_for_each_owned(optics.0, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over_owned<F>(self, optics: _mapped<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.map(|t| t.over_owned(optics.0, f))
+        }
+    }
+
+    impl_iter_ref!(<T> HashSet<T>);
+    impl_iter_ref!(<T> BTreeSet<T>);
+    impl_iter_ref!(<T> BinaryHeap<T>);
+
+    impl_iter_owned!(<T> HashSet<T>, [T: Eq + Hash,]);
+    impl_iter_owned!(<T> BTreeSet<T>, [T: Ord,]);
+    impl_iter_owned!(<T> BinaryHeap<T>, [T: Ord,]);
+
+    macro_rules! impl_values {
+        ($map:ident, $($bound:tt)*) => {
+            impl<Tr: Clone, Image: ?Sized, K, V> TraversalRef<_mapped<Tr>, Image> for $map<K, V>
+            where
+                V: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.values()
+                        .try_for_each(|v| v.try_for_each_ref(optics.0.clone(), &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K: PathKey, V> IndexedTraversalRef<_mapped<Tr>, Image> for $map<K, V>
+            where
+                V: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _mapped<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter().try_for_each(|(k, v)| {
+                        path.push(Segment::Key(k.path_key()));
+                        let flow = v.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K, V> TraversalMut<_mapped<Tr>, Image> for $map<K, V>
+            where
+                V: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.values_mut()
+                        .try_for_each(|v| v.try_for_each_mut(optics.0.clone(), &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K: PathKey, V> IndexedTraversalMut<_mapped<Tr>, Image> for $map<K, V>
+            where
+                V: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _mapped<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter_mut().try_for_each(|(k, v)| {
+                        path.push(Segment::Key(k.path_key()));
+                        let flow = v.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image, K: $($bound)*, V> Traversal<_mapped<Tr>, Image> for $map<K, V>
+            where
+                V: Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _mapped<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    self.into_iter()
+                        .try_for_each(|(_, v)| v.try_for_each_move(optics.0.clone(), &mut f))
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _mapped<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    self.into_iter()
+                        .map(|(k, v)| (k, v.over(optics.0.clone(), &mut f)))
+                        .collect()
+                }
+            }
+        };
+    }
+
+    impl_values!(HashMap, Eq + Hash);
+    impl_values!(BTreeMap, Ord);
+}
+
+mod impl_ptr {
+    use crate::*;
+    use crate::path::{OpticPath, PathMiss};
+    use std::borrow::{BorrowMut, Cow};
+    use std::ops::{ControlFlow, Deref, DerefMut};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    macro_rules! impl_ref {
+        (<$param:ident> $ptr:ty, $optic:ident) => {
+            impl<$param: ?Sized, Image: ?Sized, Tr> TraversalRef<$optic<Tr>, Image> for $ptr
+            where
+                $param: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_for_each_ref(optics.0, f)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Tr> IndexedTraversalRef<$optic<Tr>, Image> for $ptr
+            where
+                $param: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_ifor_each_ref(optics.0, path, f)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Pm> PrismRef<$optic<Pm>, Image> for $ptr
+            where
+                $param: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: $optic<Pm>) -> Option<&Image> {
+                    (**self).preview_ref(optics.0)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Pm> TryPrismRef<$optic<Pm>, Image> for $ptr
+            where
+                $param: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: $optic<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    (**self).miss_ref(optics.0, path)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Ls> LensRef<$optic<Ls>, Image> for $ptr
+            where
+                $param: LensRef<Ls, Image>,
+            {
+                #[inline]
+                fn view_ref(&self, optics: $optic<Ls>) -> &Image {
+                    (**self).view_ref(optics.0)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_mut {
+        (<$param:ident> $ptr:ty, $optic:ident) => {
+            impl<$param: ?Sized, Image: ?Sized, Tr> TraversalMut<$optic<Tr>, Image> for $ptr
+            where
+                $param: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: $optic<Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_for_each_mut(optics.0, f)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Tr> IndexedTraversalMut<$optic<Tr>, Image> for $ptr
+            where
+                $param: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: $optic<Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_ifor_each_mut(optics.0, path, f)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Pm> PrismMut<$optic<Pm>, Image> for $ptr
+            where
+                $param: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: $optic<Pm>) -> Option<&mut Image> {
+                    (**self).preview_mut(optics.0)
+                }
+            }
+
+            impl<$param: ?Sized, Image: ?Sized, Ls> LensMut<$optic<Ls>, Image> for $ptr
+            where
+                $param: LensMut<Ls, Image>,
+            {
+                #[inline]
+                fn view_mut(&mut self, optics: $optic<Ls>) -> &mut Image {
+                    (**self).view_mut(optics.0)
+                }
+            }
+        };
+    }
+
+    impl<Rv, Image, T> Review<_box<Rv>, Image> for Box<T>
+    where
+        T: Review<Rv, Image>,
+    {
+        #[inline]
+        fn review(optics: _box<Rv>, from: Image) -> Self {
+            Box::new(Review::review(optics.0, from))
+        }
+    }
+
+    impl<Tr, Image, T> Traversal<_box<Tr>, Image> for Box<T>
+    where
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _box<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            (*self).try_for_each_move(optics.0, f)
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _box<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            Box::new((*self).over(optics.0, f))
+        }
+    }
+
+    impl<Pm, Image, T> Prism<_box<Pm>, Image> for Box<T>
+    where
+        T: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _box<Pm>) -> Option<Image>
+        where
+            Self: Sized,
+        {
+            (*self).preview(optics.0)
+        }
+    }
+
+    impl<Ls, Image, T> Lens<_box<Ls>, Image> for Box<T>
+    where
+        T: Lens<Ls, Image>,
+    {
+        #[inline]
+        fn view(self, optics: _box<Ls>) -> Image
+        where
+            Self: Sized,
+        {
+            (*self).view(optics.0)
+        }
+    }
+
+    impl<Is, Image, T> Iso<_box<Is>, Image> for Box<T>
+    where
+        T: Iso<Is, Image>,
+    {
+        #[inline]
+        fn to(self, optics: _box<Is>) -> Image {
+            (*self).to(optics.0)
+        }
+    }
+
+    impl<Rv, Image, T> Review<_box<Rv>, Image> for Rc<T>
+    where
+        T: Review<Rv, Image>,
+    {
+        #[inline]
+        fn review(optics: _box<Rv>, from: Image) -> Self {
+            Rc::new(Review::review(optics.0, from))
+        }
+    }
+
+    impl<Rv, Image, T> Review<_box<Rv>, Image> for Arc<T>
+    where
+        T: Review<Rv, Image>,
+    {
+        #[inline]
+        fn review(optics: _box<Rv>, from: Image) -> Self {
+            Arc::new(Review::review(optics.0, from))
+        }
+    }
+
+    impl_ref!(<T> Box<T>, _ref);
+    impl_ref!(<T> Box<T>, _mut);
+    impl_ref!(<T> Box<T>, _box);
+    impl_ref!(<T> Rc<T>, _ref);
+    impl_ref!(<T> Arc<T>, _ref);
+    impl_ref!(<T> &'_ mut T, _ref);
+    impl_ref!(<T> &'_ mut T, _mut);
+    impl_ref!(<T> &'_ T, _ref);
+
+    impl_mut!(<T> Box<T>, _box);
+    impl_mut!(<T> Box<T>, _mut);
+    impl_mut!(<T> &'_ mut T, _mut);
+
+    // the shared data is read as `$param`, cloned on write into `$owned`,
+    // written as the `$param` borrowed from it, and moved out as `$owned`
+    macro_rules! impl_cow {
+        (
+            <$($lt:lifetime,)? $param:ident> $ptr:ty, $owned:ty, [$($bound:tt)*],
+            |$this:ident| $make_mut:expr, $into_owned:expr, $new:path
+        ) => {
+            impl<$($lt,)? $param, Image: ?Sized, Tr> TraversalRef<_cow<Tr>, Image> for $ptr
+            where
+                $($bound)*
+                $param: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _cow<Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_for_each_ref(optics.0, f)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Tr> IndexedTraversalRef<_cow<Tr>, Image> for $ptr
+            where
+                $($bound)*
+                $param: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _cow<Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (**self).try_ifor_each_ref(optics.0, path, f)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Pm> PrismRef<_cow<Pm>, Image> for $ptr
+            where
+                $($bound)*
+                $param: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _cow<Pm>) -> Option<&Image> {
+                    (**self).preview_ref(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Pm> TryPrismRef<_cow<Pm>, Image> for $ptr
+            where
+                $($bound)*
+                $param: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _cow<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    (**self).miss_ref(optics.0, path)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Ls> LensRef<_cow<Ls>, Image> for $ptr
+            where
+                $($bound)*
+                $param: LensRef<Ls, Image>,
+            {
+                #[inline]
+                fn view_ref(&self, optics: _cow<Ls>) -> &Image {
+                    (**self).view_ref(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Tr> TraversalMut<_cow<Tr>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param>,
+                $param: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _cow<Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $this = self;
+                    BorrowMut::<$param>::borrow_mut($make_mut).try_for_each_mut(optics.0, f)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Tr> IndexedTraversalMut<_cow<Tr>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param>,
+                $param: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _cow<Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $this = self;
+                    BorrowMut::<$param>::borrow_mut($make_mut).try_ifor_each_mut(optics.0, path, f)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Pm> PrismMut<_cow<Pm>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param>,
+                $param: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _cow<Pm>) -> Option<&mut Image> {
+                    let $this = self;
+                    BorrowMut::<$param>::borrow_mut($make_mut).preview_mut(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image: ?Sized, Ls> LensMut<_cow<Ls>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param>,
+                $param: LensMut<Ls, Image>,
+            {
+                #[inline]
+                fn view_mut(&mut self, optics: _cow<Ls>) -> &mut Image {
+                    let $this = self;
+                    BorrowMut::<$param>::borrow_mut($make_mut).view_mut(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image, Tr> Traversal<_cow<Tr>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param> + Traversal<Tr, Image>,
+                $param: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _cow<Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let $this = self;
+                    $into_owned.try_for_each_move(optics.0, f)
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _cow<Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let $this = self;
+                    $new($into_owned.over(optics.0, f))
+                }
+            }
+
+            impl<$($lt,)? $param, Image, Pm> Prism<_cow<Pm>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param> + Prism<Pm, Image>,
+                $param: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview(self, optics: _cow<Pm>) -> Option<Image> {
+                    let $this = self;
+                    $into_owned.preview(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image, Ls> Lens<_cow<Ls>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: BorrowMut<$param> + Lens<Ls, Image>,
+                $param: LensMut<Ls, Image>,
+            {
+                #[inline]
+                fn view(self, optics: _cow<Ls>) -> Image {
+                    let $this = self;
+                    $into_owned.view(optics.0)
+                }
+            }
+
+            impl<$($lt,)? $param, Image, Rv> Review<_cow<Rv>, Image> for $ptr
+            where
+                $($bound)*
+                $owned: Review<Rv, Image>,
+            {
+                #[inline]
+                fn review(optics: _cow<Rv>, from: Image) -> Self {
+                    $new(Review::review(optics.0, from))
+                }
+            }
+        };
+    }
+
+    impl_cow!(
+        <T> Rc<T>, T, [T: Clone,],
+        |this| Rc::make_mut(this), Rc::unwrap_or_clone(this), Rc::new
+    );
+    impl_cow!(
+        <T> Arc<T>, T, [T: Clone,],
+        |this| Arc::make_mut(this), Arc::unwrap_or_clone(this), Arc::new
+    );
+    impl_cow!(
+        <'b, T> Cow<'b, T>, T::Owned, [T: ToOwned + ?Sized,],
+        |this| this.to_mut(), this.into_owned(), Cow::Owned
+    );
+
+    impl<T: ?Sized, Image: ?Sized, Tr> TraversalRef<_deref<Tr>, Image> for T
+    where
+        T: Deref,
+        T::Target: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _deref<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.deref().try_for_each_ref(optics.0, f)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Tr> IndexedTraversalRef<_deref<Tr>, Image> for T
+    where
+        T: Deref,
+        T::Target: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _deref<Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.deref().try_ifor_each_ref(optics.0, path, f)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Pm> PrismRef<_deref<Pm>, Image> for T
+    where
+        T: Deref,
+        T::Target: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _deref<Pm>) -> Option<&Image> {
+            self.deref().preview_ref(optics.0)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Pm> TryPrismRef<_deref<Pm>, Image> for T
+    where
+        T: Deref,
+        T::Target: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _deref<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            self.deref().miss_ref(optics.0, path)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Ls> LensRef<_deref<Ls>, Image> for T
+    where
+        T: Deref,
+        T::Target: LensRef<Ls, Image>,
+    {
+        #[inline]
+        fn view_ref(&self, optics: _deref<Ls>) -> &Image {
+            self.deref().view_ref(optics.0)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Tr> TraversalMut<_deref<Tr>, Image> for T
+    where
+        T: DerefMut,
+        T::Target: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _deref<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.deref_mut().try_for_each_mut(optics.0, f)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Tr> IndexedTraversalMut<_deref<Tr>, Image> for T
+    where
+        T: DerefMut,
+        T::Target: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _deref<Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.deref_mut().try_ifor_each_mut(optics.0, path, f)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Pm> PrismMut<_deref<Pm>, Image> for T
+    where
+        T: DerefMut,
+        T::Target: PrismMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: _deref<Pm>) -> Option<&mut Image> {
+            self.deref_mut().preview_mut(optics.0)
+        }
+    }
+
+    impl<T: ?Sized, Image: ?Sized, Ls> LensMut<_deref<Ls>, Image> for T
+    where
+        T: DerefMut,
+        T::Target: LensMut<Ls, Image>,
+    {
+        #[inline]
+        fn view_mut(&mut self, optics: _deref<Ls>) -> &mut Image {
+            self.deref_mut().view_mut(optics.0)
+        }
+    }
+}
+
+mod impl_iso {
+    /***********************************************************
+     * impl for _re and _inner
+     ************************************************************/
+    use crate::*;
+    use crate::path::{OpticPath, PathMiss};
+    use std::ops::ControlFlow;
+
+    impl<Is, Source, Image> Review<_re<Is, __>, Image> for Source
+    where
+        Image: Iso<Is, Source>,
+    {
+        #[inline]
+        fn review(optics: _re<Is, __>, from: Image) -> Self {
+            from.to(optics.1)
+        }
+    }
+
+    impl<Is, Source, Image> Iso<_re<Is, __>, Image> for Source
+    where
+        Image: Iso<Is, Source>,
+    {
+        #[inline]
+        fn to(self, optics: _re<Is, __>) -> Image {
+            Review::review(optics.1, self)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Tr> TraversalRef<_inner<Tr>, Image> for T
+    where
+        T::Inner: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _inner<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.inner_ref().try_for_each_ref(optics.0, f)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Tr> IndexedTraversalRef<_inner<Tr>, Image> for T
+    where
+        T::Inner: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _inner<Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.inner_ref().try_ifor_each_ref(optics.0, path, f)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Pm> PrismRef<_inner<Pm>, Image> for T
+    where
+        T::Inner: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _inner<Pm>) -> Option<&Image> {
+            self.inner_ref().preview_ref(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Pm> TryPrismRef<_inner<Pm>, Image> for T
+    where
+        T::Inner: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _inner<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            self.inner_ref().miss_ref(optics.0, path)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Ls> LensRef<_inner<Ls>, Image> for T
+    where
+        T::Inner: LensRef<Ls, Image>,
+    {
+        #[inline]
+        fn view_ref(&self, optics: _inner<Ls>) -> &Image {
+            self.inner_ref().view_ref(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Tr> TraversalMut<_inner<Tr>, Image> for T
+    where
+        T::Inner: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _inner<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.inner_mut().try_for_each_mut(optics.0, f)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Tr> IndexedTraversalMut<_inner<Tr>, Image> for T
+    where
+        T::Inner: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _inner<Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.inner_mut().try_ifor_each_mut(optics.0, path, f)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Pm> PrismMut<_inner<Pm>, Image> for T
+    where
+        T::Inner: PrismMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: _inner<Pm>) -> Option<&mut Image> {
+            self.inner_mut().preview_mut(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image: ?Sized, Ls> LensMut<_inner<Ls>, Image> for T
+    where
+        T::Inner: LensMut<Ls, Image>,
+    {
+        #[inline]
+        fn view_mut(&mut self, optics: _inner<Ls>) -> &mut Image {
+            self.inner_mut().view_mut(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image, Tr> Traversal<_inner<Tr>, Image> for T
+    where
+        T::Inner: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _inner<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.into_inner().try_for_each_move(optics.0, f)
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _inner<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            T::from_inner(self.into_inner().over(optics.0, f))
+        }
+    }
+
+    impl<T: Newtype, Image, Pm> Prism<_inner<Pm>, Image> for T
+    where
+        T::Inner: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _inner<Pm>) -> Option<Image> {
+            self.into_inner().preview(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image, Ls> Lens<_inner<Ls>, Image> for T
+    where
+        T::Inner: Lens<Ls, Image>,
+    {
+        #[inline]
+        fn view(self, optics: _inner<Ls>) -> Image {
+            self.into_inner().view(optics.0)
+        }
+    }
+
+    impl<T: Newtype, Image, Rv> Review<_inner<Rv>, Image> for T
+    where
+        T::Inner: Review<Rv, Image>,
+    {
+        #[inline]
+        fn review(optics: _inner<Rv>, from: Image) -> Self {
+            T::from_inner(Review::review(optics.0, from))
+        }
+    }
+
+    impl<T: Newtype, Image, Is> Iso<_inner<Is>, Image> for T
+    where
+        T::Inner: Iso<Is, Image>,
+    {
+        #[inline]
+        fn to(self, optics: _inner<Is>) -> Image {
+            self.into_inner().to(optics.0)
+        }
+    }
+}
+
+mod impl_ix {
+    use crate::*;
+    use crate::path::{Miss, OpticPath, PathKey, PathMiss, Segment};
+    use std::borrow::Borrow;
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use std::hash::Hash;
+    use std::fmt::Debug;
+    use std::ops::{ControlFlow, Range, RangeFrom, RangeFull, RangeTo};
+
+    // the misses of `_ix` and `_at`, when the index is out of bounds or the key is absent
+    pub(super) fn out_of_bounds<I: Debug>(
+        path: &OpticPath,
+        index: &I,
+        len: usize,
+    ) -> Option<PathMiss> {
+        let index = format!("{:?}", index);
+        Some(PathMiss::new(path, Miss::Index { index, len }))
+    }
+
+    pub(super) fn no_entry<Q: PathKey + ?Sized>(path: &OpticPath, key: &Q) -> Option<PathMiss> {
+        let key = key.path_key();
+        Some(PathMiss::new(path, Miss::Key { key }))
+    }
+
+    pub(super) fn no_focus(path: &OpticPath, optic: &'static str) -> Option<PathMiss> {
+        Some(PathMiss::new(path, Miss::NoFocus { optic }))
+    }
+
+    // the segment of the path recorded for the index of `_ix` and `_at`
+    pub(super) trait IxSegment {
+        fn segment(&self) -> Segment;
+    }
+
+    impl IxSegment for usize {
+        fn segment(&self) -> Segment {
+            Segment::Index(*self)
+        }
+    }
+
+    macro_rules! impl_range_segment {
+        ($($range:ty),*) => {
+            $(
+                impl IxSegment for $range {
+                    fn segment(&self) -> Segment {
+                        Segment::Key(format!("{:?}", self))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_range_segment!(Range<usize>, RangeTo<usize>, RangeFrom<usize>, RangeFull);
+
+    macro_rules! impl_ix {
+        (<$($param:ident,)? $(const $c:ident: $ct: ty)?> $t:ty[$ix:ty]: $o:ty) => {
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalRef<_ix<$ix, Tr>, Image> for $t
+            where
+                $o: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _ix<$ix, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self[optics.1].try_for_each_ref(optics.0, f)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> IndexedTraversalRef<_ix<$ix, Tr>, Image> for $t
+            where
+                $o: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _ix<$ix, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(optics.1.segment());
+                    let flow = self[optics.1].try_ifor_each_ref(optics.0, path, f);
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalMut<_ix<$ix, Tr>, Image> for $t
+            where
+                $o: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _ix<$ix, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self[optics.1].try_for_each_mut(optics.0, f)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> IndexedTraversalMut<_ix<$ix, Tr>, Image> for $t
+            where
+                $o: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _ix<$ix, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(optics.1.segment());
+                    let flow = self[optics.1].try_ifor_each_mut(optics.0, path, f);
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismRef<_ix<$ix, Pm>, Image> for $t
+            where
+                $o: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _ix<$ix, Pm>) -> Option<&Image> {
+                    self.get(optics.1)?.preview_ref(optics.0)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> TryPrismRef<_ix<$ix, Pm>, Image> for $t
+            where
+                $o: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _ix<$ix, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    match self.get(optics.1.clone()) {
+                        Some(x) => {
+                            path.push(optics.1.segment());
+                            let miss = x.miss_ref(optics.0, path);
+                            path.pop();
+                            miss
+                        }
+                        None => out_of_bounds(path, &optics.1, self.len()),
+                    }
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismMut<_ix<$ix, Pm>, Image> for $t
+            where
+                $o: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _ix<$ix, Pm>) -> Option<&mut Image> {
+                    self.get_mut(optics.1)?.preview_mut(optics.0)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Ls, $(const $c: $ct)?> LensRef<_ix<$ix, Ls>, Image> for $t
+            where
+                $o: LensRef<Ls, Image>,
+            {
+                #[inline]
+                fn view_ref(&self, optics: _ix<$ix, Ls>) -> &Image {
+                    self[optics.1].view_ref(optics.0)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> LensMut<_ix<$ix, Pm>, Image> for $t
+            where
+                $o: LensMut<Pm, Image>,
+            {
+                #[inline]
+                fn view_mut(&mut self, optics: _ix<$ix, Pm>) -> &mut Image {
+                    self[optics.1].view_mut(optics.0)
+                }
+            }
+        }
+    }
+
+    impl_ix!(<T,> Vec<T>[usize]: T);
+    impl_ix!(<T,> Vec<T>[Range<usize>]: [T]);
+    impl_ix!(<T,> Vec<T>[RangeTo<usize>]: [T]);
+    impl_ix!(<T,> Vec<T>[RangeFrom<usize>]: [T]);
+    impl_ix!(<T,> Vec<T>[RangeFull]: [T]);
+
+    impl_ix!(<T,> VecDeque<T>[usize]: T);
+
+    impl_ix!(<T, const N: usize> [T; N][usize]: T);
+    impl_ix!(<T, const N: usize> [T; N][Range<usize>]: [T]);
+    impl_ix!(<T, const N: usize> [T; N][RangeTo<usize>]: [T]);
+    impl_ix!(<T, const N: usize> [T; N][RangeFrom<usize>]: [T]);
+    impl_ix!(<T, const N: usize> [T; N][RangeFull]: [T]);
+
+    impl_ix!(<T,> [T][usize]: T);
+    impl_ix!(<T,> [T][Range<usize>]: [T]);
+    impl_ix!(<T,> [T][RangeTo<usize>]: [T]);
+    impl_ix!(<T,> [T][RangeFrom<usize>]: [T]);
+    impl_ix!(<T,> [T][RangeFull]: [T]);
+
+    impl_ix!(<> String[Range<usize>]: str);
+    impl_ix!(<> String[RangeTo<usize>]: str);
+    impl_ix!(<> String[RangeFrom<usize>]: str);
+    impl_ix!(<> String[RangeFull]: str);
+
+    impl_ix!(<> str[Range<usize>]: str);
+    impl_ix!(<> str[RangeTo<usize>]: str);
+    impl_ix!(<> str[RangeFrom<usize>]: str);
+    impl_ix!(<> str[RangeFull]: str);
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalRef<_ix<&'_ Q, Tr>, Image> for BTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+        V: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _ix<&Q, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self[optics.1].try_for_each_ref(optics.0, f)
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> IndexedTraversalRef<_ix<&'_ Q, Tr>, Image> for BTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + PathKey,
+        V: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _ix<&Q, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            path.push(Segment::Key(optics.1.path_key()));
+            let flow = self[optics.1].try_ifor_each_ref(optics.0, path, f);
+            path.pop();
+            flow
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalRef<_ix<&'_ Q, Tr>, Image> for HashMap<K, V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash,
+        V: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _ix<&Q, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self[optics.1].try_for_each_ref(optics.0, f)
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Tr> IndexedTraversalRef<_ix<&'_ Q, Tr>, Image> for HashMap<K, V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + PathKey,
+        V: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _ix<&Q, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            path.push(Segment::Key(optics.1.path_key()));
+            let flow = self[optics.1].try_ifor_each_ref(optics.0, path, f);
+            path.pop();
+            flow
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismRef<_ix<&'_ Q, Pm>, Image> for BTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+        V: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _ix<&Q, Pm>) -> Option<&Image> {
+            self.get(optics.1)?.preview_ref(optics.0)
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> TryPrismRef<_ix<&'_ Q, Pm>, Image> for BTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + PathKey,
+        V: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _ix<&Q, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            match self.get(optics.1) {
+                Some(x) => {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let miss = x.miss_ref(optics.0, path);
+                    path.pop();
+                    miss
+                }
+                None => no_entry(path, optics.1),
+            }
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismRef<_ix<&'_ Q, Pm>, Image> for HashMap<K, V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash,
+        V: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _ix<&Q, Pm>) -> Option<&Image> {
+            self.get(optics.1)?.preview_ref(optics.0)
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Pm> TryPrismRef<_ix<&'_ Q, Pm>, Image> for HashMap<K, V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash + PathKey,
+        V: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _ix<&Q, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            match self.get(optics.1) {
+                Some(x) => {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let miss = x.miss_ref(optics.0, path);
+                    path.pop();
+                    miss
+                }
+                None => no_entry(path, optics.1),
+            }
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Ls> LensRef<_ix<&'_ Q, Ls>, Image> for BTreeMap<K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+        V: LensRef<Ls, Image>,
+    {
+        #[inline]
+        fn view_ref(&self, optics: _ix<&Q, Ls>) -> &Image {
+            self[optics.1].view_ref(optics.0)
+        }
+    }
+
+    impl<K, Q: ?Sized, V, Image: ?Sized, Ls> LensRef<_ix<&'_ Q, Ls>, Image> for HashMap<K, V>
+    where
+        K: Eq + Hash + Borrow<Q>,
+        Q: Eq + Hash,
+        V: LensRef<Ls, Image>,
+    {
+        #[inline]
+        fn view_ref(&self, optics: _ix<&Q, Ls>) -> &Image {
+            self[optics.1].view_ref(optics.0)
+        }
+    }
+}
+
+mod impl_at {
+    /***********************************************************
+     * impl for _at
+     ************************************************************/
+    use super::impl_ix::{no_entry, out_of_bounds, IxSegment};
+    use crate::path::{OpticPath, PathKey, PathMiss, Segment};
+    use crate::*;
+    use std::borrow::Borrow;
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use std::hash::Hash;
+    use std::ops::{ControlFlow, Range, RangeFrom, RangeFull, RangeTo};
+
+    macro_rules! impl_at_ref {
+        (<$($param:ident,)? $(const $c:ident: $ct: ty)?> $t:ty[$ix:ty]: $o:ty) => {
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalRef<_at<$ix, Tr>, Image> for $t
+            where
+                $o: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _at<$ix, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self.get(optics.1) {
+                        Some(x) => x.try_for_each_ref(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> IndexedTraversalRef<_at<$ix, Tr>, Image> for $t
+            where
+                $o: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _at<$ix, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(optics.1.segment());
+                    let flow = match self.get(optics.1) {
+                        Some(x) => x.try_ifor_each_ref(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> TraversalMut<_at<$ix, Tr>, Image> for $t
+            where
+                $o: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _at<$ix, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self.get_mut(optics.1) {
+                        Some(x) => x.try_for_each_mut(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Tr, $(const $c: $ct)?> IndexedTraversalMut<_at<$ix, Tr>, Image> for $t
+            where
+                $o: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _at<$ix, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(optics.1.segment());
+                    let flow = match self.get_mut(optics.1) {
+                        Some(x) => x.try_ifor_each_mut(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismRef<_at<$ix, Pm>, Image> for $t
+            where
+                $o: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _at<$ix, Pm>) -> Option<&Image> {
+                    self.get(optics.1)?.preview_ref(optics.0)
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> TryPrismRef<_at<$ix, Pm>, Image> for $t
+            where
+                $o: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _at<$ix, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    match self.get(optics.1.clone()) {
+                        Some(x) => {
+                            path.push(optics.1.segment());
+                            let miss = x.miss_ref(optics.0, path);
+                            path.pop();
+                            miss
+                        }
+                        None => out_of_bounds(path, &optics.1, self.len()),
+                    }
+                }
+            }
+
+            impl<$($param,)? Image: ?Sized, Pm, $(const $c: $ct)?> PrismMut<_at<$ix, Pm>, Image> for $t
+            where
+                $o: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _at<$ix, Pm>) -> Option<&mut Image> {
+                    self.get_mut(optics.1)?.preview_mut(optics.0)
+                }
+            }
+        }
+    }
+
+    impl_at_ref!(<T,> Vec<T>[usize]: T);
+    impl_at_ref!(<T,> Vec<T>[Range<usize>]: [T]);
+    impl_at_ref!(<T,> Vec<T>[RangeTo<usize>]: [T]);
+    impl_at_ref!(<T,> Vec<T>[RangeFrom<usize>]: [T]);
+    impl_at_ref!(<T,> Vec<T>[RangeFull]: [T]);
+
+    impl_at_ref!(<T,> VecDeque<T>[usize]: T);
+
+    impl_at_ref!(<T, const N: usize> [T; N][usize]: T);
+    impl_at_ref!(<T, const N: usize> [T; N][Range<usize>]: [T]);
+    impl_at_ref!(<T, const N: usize> [T; N][RangeTo<usize>]: [T]);
+    impl_at_ref!(<T, const N: usize> [T; N][RangeFrom<usize>]: [T]);
+    impl_at_ref!(<T, const N: usize> [T; N][RangeFull]: [T]);
+
+    impl_at_ref!(<T,> [T][usize]: T);
+    impl_at_ref!(<T,> [T][Range<usize>]: [T]);
+    impl_at_ref!(<T,> [T][RangeTo<usize>]: [T]);
+    impl_at_ref!(<T,> [T][RangeFrom<usize>]: [T]);
+    impl_at_ref!(<T,> [T][RangeFull]: [T]);
+
+    impl_at_ref!(<> String[Range<usize>]: str);
+    impl_at_ref!(<> String[RangeTo<usize>]: str);
+    impl_at_ref!(<> String[RangeFrom<usize>]: str);
+    impl_at_ref!(<> String[RangeFull]: str);
+
+    impl_at_ref!(<> str[Range<usize>]: str);
+    impl_at_ref!(<> str[RangeTo<usize>]: str);
+    impl_at_ref!(<> str[RangeFrom<usize>]: str);
+    impl_at_ref!(<> str[RangeFull]: str);
+
+    impl<T, Image, Tr> Traversal<_at<usize, Tr>, Image> for Vec<T>
+    where
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _at<usize, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match self.into_iter().nth(optics.1) {
+                Some(x) => x.try_for_each_move(optics.0, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _at<usize, Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            // swap the focus out with the last item and back, instead of shifting the rest twice
+            let mut this = self;
+            if optics.1 < this.len() {
+                let x = this.swap_remove(optics.1);
+                this.push(x.over(optics.0, f));
+                let last = this.len() - 1;
+                this.swap(optics.1, last);
+            }
+            this
+        }
+    }
+
+    impl<T, Image, Pm> Prism<_at<usize, Pm>, Image> for Vec<T>
+    where
+        T: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _at<usize, Pm>) -> Option<Image> {
+            self.into_iter().nth(optics.1)?.preview(optics.0)
+        }
+    }
+
+    impl<T, Image, Tr> Traversal<_at<usize, Tr>, Image> for VecDeque<T>
+    where
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _at<usize, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match self.into_iter().nth(optics.1) {
+                Some(x) => x.try_for_each_move(optics.0, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _at<usize, Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            // the same as `Vec`
+            let mut this = self;
+            if let Some(x) = this.swap_remove_back(optics.1) {
+                this.push_back(x.over(optics.0, f));
+                let last = this.len() - 1;
+                this.swap(optics.1, last);
+            }
+            this
+        }
+    }
+
+    impl<T, Image, Pm> Prism<_at<usize, Pm>, Image> for VecDeque<T>
+    where
+        T: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _at<usize, Pm>) -> Option<Image> {
+            self.into_iter().nth(optics.1)?.preview(optics.0)
+        }
+    }
+
+    impl<T, Image, Tr, const N: usize> Traversal<_at<usize, Tr>, Image> for [T; N]
+    where
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _at<usize, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match IntoIterator::into_iter(self).nth(optics.1) {
+                Some(x) => x.try_for_each_move(optics.0, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _at<usize, Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            let _at(optic, index) = optics;
+            let mut optic = Some(optic);
+            let mut i = 0;
+            self.map(|x| {
+                let x = match optic.take() {
+                    Some(tr) if i == index => x.over(tr, &mut f),
+                    tr => {
+                        optic = tr;
+                        x
+                    }
+                };
+                i += 1;
+                x
+            })
+        }
+    }
+
+    impl<T, Image, Pm, const N: usize> Prism<_at<usize, Pm>, Image> for [T; N]
+    where
+        T: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _at<usize, Pm>) -> Option<Image> {
+            IntoIterator::into_iter(self).nth(optics.1)?.preview(optics.0)
+        }
+    }
+
+    macro_rules! impl_at_map {
+        ($map:ident, $($bound:tt)*) => {
+            impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalRef<_at<&'_ Q, Tr>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _at<&Q, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self.get(optics.1) {
+                        Some(x) => x.try_for_each_ref(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Tr> IndexedTraversalRef<_at<&'_ Q, Tr>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: PathKey + $($bound)*,
+                V: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _at<&Q, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let flow = match self.get(optics.1) {
+                        Some(x) => x.try_ifor_each_ref(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Tr> TraversalMut<_at<&'_ Q, Tr>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _at<&Q, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self.get_mut(optics.1) {
+                        Some(x) => x.try_for_each_mut(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Tr> IndexedTraversalMut<_at<&'_ Q, Tr>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: PathKey + $($bound)*,
+                V: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _at<&Q, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let flow = match self.get_mut(optics.1) {
+                        Some(x) => x.try_ifor_each_mut(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image, Tr> Traversal<_at<&'_ Q, Tr>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _at<&Q, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let mut this = self;
+                    match this.remove(optics.1) {
+                        Some(x) => x.try_for_each_move(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _at<&Q, Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let mut this = self;
+                    if let Some((k, x)) = this.remove_entry(optics.1) {
+                        this.insert(k, x.over(optics.0, f));
+                    }
+                    this
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismRef<_at<&'_ Q, Pm>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _at<&Q, Pm>) -> Option<&Image> {
+                    self.get(optics.1)?.preview_ref(optics.0)
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Pm> TryPrismRef<_at<&'_ Q, Pm>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: PathKey + $($bound)*,
+                V: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _at<&Q, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    match self.get(optics.1) {
+                        Some(x) => {
+                            path.push(Segment::Key(optics.1.path_key()));
+                            let miss = x.miss_ref(optics.0, path);
+                            path.pop();
+                            miss
+                        }
+                        None => no_entry(path, optics.1),
+                    }
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image: ?Sized, Pm> PrismMut<_at<&'_ Q, Pm>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _at<&Q, Pm>) -> Option<&mut Image> {
+                    self.get_mut(optics.1)?.preview_mut(optics.0)
+                }
+            }
+
+            impl<K, Q: ?Sized, V, Image, Pm> Prism<_at<&'_ Q, Pm>, Image> for $map<K, V>
+            where
+                K: Borrow<Q> + $($bound)*,
+                Q: $($bound)*,
+                V: Prism<Pm, Image>,
+            {
+                #[inline]
+                fn preview(self, optics: _at<&Q, Pm>) -> Option<Image> {
+                    let mut this = self;
+                    this.remove(optics.1)?.preview(optics.0)
+                }
+            }
+        };
+    }
+
+    impl_at_map!(HashMap, Eq + Hash);
+    impl_at_map!(BTreeMap, Ord);
+}
+
+mod impl_entry {
+    /***********************************************************
+     * impl for _entry, _non and _or_default
+     * reading never inserts a value: an absent slot has no focus for the traversals and previews,
+     * `view` falls back on the default, and `view_ref`, with nowhere to keep the default,
+     * panics on it like `map[key]`,
+     * while writing (`view_mut`, `preview_mut`, `for_each_mut` and `over`) inserts the default first
+     ************************************************************/
+    use super::impl_ix::{no_entry, no_focus};
+    use crate::path::{OpticPath, PathKey, PathMiss, Segment};
+    use crate::*;
+    use std::collections::{BTreeMap, HashMap};
+    use std::hash::Hash;
+    use std::ops::ControlFlow;
+
+    macro_rules! impl_entry {
+        ($map:ident, $($bound:tt)*) => {
+            impl<K, V, Image: ?Sized, Tr> TraversalRef<_entry<K, Tr>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _entry<K, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self.get(&optics.1) {
+                        Some(x) => x.try_for_each_ref(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Tr> IndexedTraversalRef<_entry<K, Tr>, Image> for $map<K, V>
+            where
+                K: PathKey + $($bound)*,
+                V: Default + IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _entry<K, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let flow = match self.get(&optics.1) {
+                        Some(x) => x.try_ifor_each_ref(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Tr> TraversalMut<_entry<K, Tr>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _entry<K, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.entry(optics.1).or_default().try_for_each_mut(optics.0, f)
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Tr> IndexedTraversalMut<_entry<K, Tr>, Image> for $map<K, V>
+            where
+                K: PathKey + $($bound)*,
+                V: Default + IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _entry<K, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(Segment::Key(optics.1.path_key()));
+                    let flow = self.entry(optics.1).or_default().try_ifor_each_mut(optics.0, path, f);
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<K, V, Image, Tr> Traversal<_entry<K, Tr>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _entry<K, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let mut this = self;
+                    match this.remove(&optics.1) {
+                        Some(x) => x.try_for_each_move(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _entry<K, Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let mut this = self;
+                    let x = this.remove(&optics.1).unwrap_or_default();
+                    this.insert(optics.1, x.over(optics.0, f));
+                    this
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Pm> PrismRef<_entry<K, Pm>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _entry<K, Pm>) -> Option<&Image> {
+                    self.get(&optics.1)?.preview_ref(optics.0)
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Pm> TryPrismRef<_entry<K, Pm>, Image> for $map<K, V>
+            where
+                K: PathKey + $($bound)*,
+                V: Default + TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _entry<K, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    match self.get(&optics.1) {
+                        Some(x) => {
+                            path.push(Segment::Key(optics.1.path_key()));
+                            let miss = x.miss_ref(optics.0, path);
+                            path.pop();
+                            miss
+                        }
+                        None => no_entry(path, &optics.1),
+                    }
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Pm> PrismMut<_entry<K, Pm>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _entry<K, Pm>) -> Option<&mut Image> {
+                    self.entry(optics.1).or_default().preview_mut(optics.0)
+                }
+            }
+
+            impl<K, V, Image, Pm> Prism<_entry<K, Pm>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + Prism<Pm, Image>,
+            {
+                #[inline]
+                fn preview(self, optics: _entry<K, Pm>) -> Option<Image> {
+                    let mut this = self;
+                    this.remove(&optics.1)?.preview(optics.0)
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Ls> LensRef<_entry<K, Ls>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + LensRef<Ls, Image>,
+            {
+                #[inline]
+                fn view_ref(&self, optics: _entry<K, Ls>) -> &Image {
+                    self[&optics.1].view_ref(optics.0)
+                }
+            }
+
+            impl<K, V, Image: ?Sized, Ls> LensMut<_entry<K, Ls>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + LensMut<Ls, Image>,
+            {
+                #[inline]
+                fn view_mut(&mut self, optics: _entry<K, Ls>) -> &mut Image {
+                    self.entry(optics.1).or_default().view_mut(optics.0)
+                }
+            }
+
+            impl<K, V, Image, Ls> Lens<_entry<K, Ls>, Image> for $map<K, V>
+            where
+                K: $($bound)*,
+                V: Default + Lens<Ls, Image>,
+            {
+                #[inline]
+                fn view(self, optics: _entry<K, Ls>) -> Image {
+                    let mut this = self;
+                    this.remove(&optics.1).unwrap_or_default().view(optics.0)
+                }
+            }
+        };
+    }
+
+    impl_entry!(HashMap, Eq + Hash);
+    impl_entry!(BTreeMap, Ord);
+
+    macro_rules! impl_non {
+        ($name:literal, $optic:ty, [$($bound:tt)*], |$optics:ident| $default:expr) => {
+            impl<T, Image: ?Sized, Tr> TraversalRef<$optic, Image> for Option<T>
+            where
+                T: TraversalRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    match self {
+                        Some(x) => x.try_for_each_ref($optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> IndexedTraversalRef<$optic, Image> for Option<T>
+            where
+                T: IndexedTraversalRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    $optics: $optic,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    path.push(Segment::Variant("Some"));
+                    let flow = match self {
+                        Some(x) => x.try_ifor_each_ref($optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> TraversalMut<$optic, Image> for Option<T>
+            where
+                T: TraversalMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    if self.is_none() {
+                        *self = Some($default);
+                    }
+                    match self {
+                        Some(x) => x.try_for_each_mut($optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> IndexedTraversalMut<$optic, Image> for Option<T>
+            where
+                T: IndexedTraversalMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, $optics: $optic, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    if self.is_none() {
+                        *self = Some($default);
+                    }
+                    path.push(Segment::Variant("Some"));
+                    let flow = match self {
+                        Some(x) => x.try_ifor_each_mut($optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<T, Image, Tr> Traversal<$optic, Image> for Option<T>
+            where
+                T: Traversal<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    match self {
+                        Some(x) => x.try_for_each_move($optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, $optics: $optic, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let x = match self {
+                        Some(x) => x,
+                        None => $default,
+                    };
+                    Some(x.over($optics.0, f))
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> PrismRef<$optic, Image> for Option<T>
+            where
+                T: PrismRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview_ref(&self, $optics: $optic) -> Option<&Image> {
+                    self.as_ref()?.preview_ref($optics.0)
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> TryPrismRef<$optic, Image> for Option<T>
+            where
+                T: TryPrismRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn miss_ref(&self, $optics: $optic, path: &mut OpticPath) -> Option<PathMiss> {
+                    match self {
+                        Some(x) => x.miss_ref($optics.0, path),
+                        None => no_focus(path, $name),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> PrismMut<$optic, Image> for Option<T>
+            where
+                T: PrismMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview_mut(&mut self, $optics: $optic) -> Option<&mut Image> {
+                    if self.is_none() {
+                        *self = Some($default);
+                    }
+                    self.as_mut()?.preview_mut($optics.0)
+                }
+            }
+
+            impl<T, Image, Tr> Prism<$optic, Image> for Option<T>
+            where
+                T: Prism<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview(self, $optics: $optic) -> Option<Image> {
+                    self?.preview($optics.0)
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> LensRef<$optic, Image> for Option<T>
+            where
+                T: LensRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn view_ref(&self, $optics: $optic) -> &Image {
+                    match self {
+                        Some(x) => x.view_ref($optics.0),
+                        None => panic!("can't view `None` without inserting the default"),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr> LensMut<$optic, Image> for Option<T>
+            where
+                T: LensMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn view_mut(&mut self, $optics: $optic) -> &mut Image {
+                    match self {
+                        Some(x) => x,
+                        None => self.get_or_insert($default),
+                    }
+                    .view_mut($optics.0)
+                }
+            }
+
+            impl<T, Image, Tr> Lens<$optic, Image> for Option<T>
+            where
+                T: Lens<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn view(self, $optics: $optic) -> Image {
+                    let x = match self {
+                        Some(x) => x,
+                        None => $default,
+                    };
+                    x.view($optics.0)
+                }
+            }
+        };
+    }
+
+    impl_non!("_non", _non<T, Tr>, [], |optics| optics.1);
+    impl_non!("_or_default", _or_default<Tr>, [T: Default,], |optics| T::default());
+}
+
+mod impl_map {
+    /***********************************************************
+     * impl for _keys, _entries and _range
+     ************************************************************/
+    use crate::path::{OpticPath, PathKey, Segment};
+    use crate::*;
+    use std::collections::{BTreeMap, HashMap};
+    use std::hash::Hash;
+    use std::ops::{Bound, ControlFlow, RangeBounds};
+
+    macro_rules! impl_map {
+        ($map:ident, $($bound:tt)*) => {
+            impl<Tr: Clone, Image: ?Sized, K, V> TraversalRef<_keys<Tr>, Image> for $map<K, V>
+            where
+                K: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _keys<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.keys()
+                        .try_for_each(|k| k.try_for_each_ref(optics.0.clone(), &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K: PathKey, V> IndexedTraversalRef<_keys<Tr>, Image> for $map<K, V>
+            where
+                K: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _keys<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.keys().try_for_each(|k| {
+                        path.push(Segment::Key(k.path_key()));
+                        let flow = k.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K, V> TraversalRef<_entries<Tr>, Image> for $map<K, V>
+            where
+                Tr: EntryRef<K, V, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _entries<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter()
+                        .try_for_each(|(k, v)| optics.0.clone().try_for_each_entry(k, v, &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K: PathKey, V> IndexedTraversalRef<_entries<Tr>, Image> for $map<K, V>
+            where
+                Tr: IndexedEntryRef<K, V, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _entries<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter().try_for_each(|(k, v)| {
+                        path.push(Segment::Key(k.path_key()));
+                        let flow = optics.0.clone().try_ifor_each_entry(k, v, path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K, V> TraversalMut<_entries<Tr>, Image> for $map<K, V>
+            where
+                Tr: EntryMut<K, V, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _entries<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter_mut()
+                        .try_for_each(|(k, v)| optics.0.clone().try_for_each_entry_mut(k, v, &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized, K: PathKey, V> IndexedTraversalMut<_entries<Tr>, Image> for $map<K, V>
+            where
+                Tr: IndexedEntryMut<K, V, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _entries<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.iter_mut().try_for_each(|(k, v)| {
+                        path.push(Segment::Key(k.path_key()));
+                        let flow = optics.0.clone().try_ifor_each_entry_mut(k, v, path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image, K: $($bound)*, V> Traversal<_entries<Tr>, Image> for $map<K, V>
+            where
+                Tr: EntryMove<K, V, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _entries<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    self.into_iter()
+                        .try_for_each(|(k, v)| optics.0.clone().try_for_each_entry_move(k, v, &mut f))
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _entries<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    self.into_iter()
+                        .map(|(k, v)| optics.0.clone().over_entry(k, v, &mut f))
+                        .collect()
+                }
+            }
+        };
+    }
+
+    impl_map!(HashMap, Eq + Hash);
+    impl_map!(BTreeMap, Ord);
+
+    // the optics going on from the entry pair `(&K, &V)` of `_entries`,
+    // which is a temporary, so they end at the key by `_0` or at the value by `_1`
+    pub trait EntryRef<K, V, Image: ?Sized> {
+        fn try_for_each_entry<'a, B, F>(self, k: &'a K, v: &'a V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a;
+    }
+
+    // telling the path of the focus in the entry pair
+    pub trait IndexedEntryRef<K, V, Image: ?Sized>: EntryRef<K, V, Image> {
+        fn try_ifor_each_entry<'a, B, F>(
+            self,
+            k: &'a K,
+            v: &'a V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a;
+    }
+
+    // the entry pair is `(&K, &mut V)` when mutating, the key is never mutable
+    pub trait EntryMut<K, V, Image: ?Sized>: EntryRef<K, V, Image> {
+        fn try_for_each_entry_mut<'a, B, F>(self, k: &'a K, v: &'a mut V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a;
+    }
+
+    pub trait IndexedEntryMut<K, V, Image: ?Sized>:
+        EntryMut<K, V, Image> + IndexedEntryRef<K, V, Image>
+    {
+        fn try_ifor_each_entry_mut<'a, B, F>(
+            self,
+            k: &'a K,
+            v: &'a mut V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a;
+    }
+
+    pub trait EntryMove<K, V, Image>: EntryMut<K, V, Image> {
+        fn try_for_each_entry_move<B, F>(self, k: K, v: V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>;
+
+        fn over_entry<F>(self, k: K, v: V, f: F) -> (K, V)
+        where
+            F: FnMut(Image) -> Image;
+    }
+
+    impl<K, V, Image: ?Sized, Tr> EntryRef<K, V, Image> for _0<Tr>
+    where
+        K: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry<'a, B, F>(self, k: &'a K, _v: &'a V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            k.try_for_each_ref(self.0, f)
+        }
+    }
+
+    impl<K, V, Image: ?Sized, Tr> IndexedEntryRef<K, V, Image> for _0<Tr>
+    where
+        K: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_entry<'a, B, F>(
+            self,
+            k: &'a K,
+            _v: &'a V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            k.try_ifor_each_ref(self.0, path, f)
+        }
+    }
+
+    impl<K, V, Image: ?Sized, Tr> EntryRef<K, V, Image> for _1<Tr>
+    where
+        V: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry<'a, B, F>(self, _k: &'a K, v: &'a V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            v.try_for_each_ref(self.0, f)
+        }
+    }
+
+    impl<K, V, Image: ?Sized, Tr> IndexedEntryRef<K, V, Image> for _1<Tr>
+    where
+        V: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_entry<'a, B, F>(
+            self,
+            _k: &'a K,
+            v: &'a V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            v.try_ifor_each_ref(self.0, path, f)
+        }
+    }
+
+    impl<K, V, Image: ?Sized, Tr> EntryMut<K, V, Image> for _1<Tr>
+    where
+        V: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry_mut<'a, B, F>(self, _k: &'a K, v: &'a mut V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            v.try_for_each_mut(self.0, f)
+        }
+    }
+
+    impl<K, V, Image: ?Sized, Tr> IndexedEntryMut<K, V, Image> for _1<Tr>
+    where
+        V: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_entry_mut<'a, B, F>(
+            self,
+            _k: &'a K,
+            v: &'a mut V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            v.try_ifor_each_mut(self.0, path, f)
+        }
+    }
+
+    impl<K, V, Image, Tr> EntryMove<K, V, Image> for _1<Tr>
+    where
+        V: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry_move<B, F>(self, _k: K, v: V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            v.try_for_each_move(self.0, f)
+        }
+
+        #[inline]
+        fn over_entry<F>(self, k: K, v: V, f: F) -> (K, V)
+        where
+            F: FnMut(Image) -> Image,
+        {
+            (k, v.over(self.0, f))
+        }
+    }
+
+    impl<K, V, Image: ?Sized, P, Tr> EntryRef<K, V, Image> for _filtered<P, Tr>
+    where
+        P: FnMut(&(&K, &V)) -> bool,
+        Tr: EntryRef<K, V, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry<'a, B, F>(self, k: &'a K, v: &'a V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(k, v)) {
+                optics.try_for_each_entry(k, v, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<K, V, Image: ?Sized, P, Tr> IndexedEntryRef<K, V, Image> for _filtered<P, Tr>
+    where
+        P: FnMut(&(&K, &V)) -> bool,
+        Tr: IndexedEntryRef<K, V, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_entry<'a, B, F>(
+            self,
+            k: &'a K,
+            v: &'a V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(k, v)) {
+                optics.try_ifor_each_entry(k, v, path, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<K, V, Image: ?Sized, P, Tr> EntryMut<K, V, Image> for _filtered<P, Tr>
+    where
+        P: FnMut(&(&K, &V)) -> bool,
+        Tr: EntryMut<K, V, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry_mut<'a, B, F>(self, k: &'a K, v: &'a mut V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(k, &*v)) {
+                optics.try_for_each_entry_mut(k, v, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<K, V, Image: ?Sized, P, Tr> IndexedEntryMut<K, V, Image> for _filtered<P, Tr>
+    where
+        P: FnMut(&(&K, &V)) -> bool,
+        Tr: IndexedEntryMut<K, V, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_entry_mut<'a, B, F>(
+            self,
+            k: &'a K,
+            v: &'a mut V,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(k, &*v)) {
+                optics.try_ifor_each_entry_mut(k, v, path, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<K, V, Image, P, Tr> EntryMove<K, V, Image> for _filtered<P, Tr>
+    where
+        P: FnMut(&(&K, &V)) -> bool,
+        Tr: EntryMove<K, V, Image>,
+    {
+        #[inline]
+        fn try_for_each_entry_move<B, F>(self, k: K, v: V, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(&k, &v)) {
+                optics.try_for_each_entry_move(k, v, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+
+        #[inline]
+        fn over_entry<F>(self, k: K, v: V, f: F) -> (K, V)
+        where
+            F: FnMut(Image) -> Image,
+        {
+            let _filtered(optics, mut pred) = self;
+            if pred(&(&k, &v)) {
+                optics.over_entry(k, v, f)
+            } else {
+                (k, v)
+            }
+        }
+    }
+
+    // `BTreeMap::range` panics on the inverted ranges and the empty excluded ones,
+    // which just have no keys in them for `_range`
+    fn is_ordered<K: Ord, R: RangeBounds<K>>(range: &R) -> bool {
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(start), Bound::Excluded(end)) => start < end,
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) => {
+                start <= end
+            }
+            _ => true,
+        }
+    }
+
+    impl<R, Tr: Clone, Image: ?Sized, K: Ord, V> TraversalRef<_range<R, Tr>, Image>
+        for BTreeMap<K, V>
+    where
+        R: RangeBounds<K>,
+        V: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _range<R, Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _range(optics, range) = optics;
+            if !is_ordered(&range) {
+                return ControlFlow::Continue(());
+            }
+            self.range(range)
+                .try_for_each(|(_, v)| v.try_for_each_ref(optics.clone(), &mut f))
+        }
+    }
+
+    impl<R, Tr: Clone, Image: ?Sized, K: PathKey + Ord, V> IndexedTraversalRef<_range<R, Tr>, Image>
+        for BTreeMap<K, V>
+    where
+        R: RangeBounds<K>,
+        V: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _range<R, Tr>,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _range(optics, range) = optics;
+            if !is_ordered(&range) {
+                return ControlFlow::Continue(());
+            }
+            self.range(range).try_for_each(|(k, v)| {
+                path.push(Segment::Key(k.path_key()));
+                let flow = v.try_ifor_each_ref(optics.clone(), path, &mut f);
+                path.pop();
+                flow
+            })
+        }
+    }
+
+    impl<R, Tr: Clone, Image: ?Sized, K: Ord, V> TraversalMut<_range<R, Tr>, Image>
+        for BTreeMap<K, V>
+    where
+        R: RangeBounds<K>,
+        V: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _range<R, Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _range(optics, range) = optics;
+            if !is_ordered(&range) {
+                return ControlFlow::Continue(());
+            }
+            self.range_mut(range)
+                .try_for_each(|(_, v)| v.try_for_each_mut(optics.clone(), &mut f))
+        }
+    }
+
+    impl<R, Tr: Clone, Image: ?Sized, K: PathKey + Ord, V> IndexedTraversalMut<_range<R, Tr>, Image>
+        for BTreeMap<K, V>
+    where
+        R: RangeBounds<K>,
+        V: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _range<R, Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _range(optics, range) = optics;
+            if !is_ordered(&range) {
+                return ControlFlow::Continue(());
+            }
+            self.range_mut(range).try_for_each(|(k, v)| {
+                path.push(Segment::Key(k.path_key()));
+                let flow = v.try_ifor_each_mut(optics.clone(), path, &mut f);
+                path.pop();
+                flow
+            })
+        }
+    }
+
+    impl<R, Tr: Clone, Image, K: Ord, V> Traversal<_range<R, Tr>, Image> for BTreeMap<K, V>
+    where
+        R: RangeBounds<K>,
+        V: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _range<R, Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            let _range(optics, range) = optics;
+            self.into_iter()
+                .filter(|(k, _)| range.contains(k))
+                .try_for_each(|(_, v)| v.try_for_each_move(optics.clone(), &mut f))
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _range<R, Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            let _range(optics, range) = optics;
+            self.into_iter()
+                .map(|(k, v)| match range.contains(&k) {
+                    true => (k, v.over(optics.clone(), &mut f)),
+                    false => (k, v),
+                })
+                .collect()
+        }
+    }
+}
+
+mod impl_predicate {
+    /***********************************************************
+     * impl for _filtered, _find, _only and _nearly
+     ************************************************************/
+    use super::impl_ix::no_focus;
+    use crate::path::{OpticPath, PathMiss, Segment};
+    use crate::*;
+    use std::collections::{LinkedList, VecDeque};
+    use std::ops::ControlFlow;
+
+    impl<P, Tr, Image: ?Sized, T: ?Sized> TraversalRef<_filtered<P, Tr>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _filtered<P, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = optics;
+            match pred(self) {
+                true => self.try_for_each_ref(optics, f),
+                false => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<P, Tr, Image: ?Sized, T: ?Sized> IndexedTraversalRef<_filtered<P, Tr>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _filtered<P, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = optics;
+            match pred(self) {
+                true => self.try_ifor_each_ref(optics, path, f),
+                false => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<P, Tr, Image: ?Sized, T: ?Sized> TraversalMut<_filtered<P, Tr>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _filtered<P, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = optics;
+            if pred(self) {
+                self.try_for_each_mut(optics, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<P, Tr, Image: ?Sized, T: ?Sized> IndexedTraversalMut<_filtered<P, Tr>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _filtered<P, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let _filtered(optics, mut pred) = optics;
+            if pred(self) {
+                self.try_ifor_each_mut(optics, path, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    impl<P, Tr, Image, T> Traversal<_filtered<P, Tr>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _filtered<P, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            let _filtered(optics, mut pred) = optics;
+            if pred(&self) {
+                self.try_for_each_move(optics, f)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _filtered<P, Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            let _filtered(optics, mut pred) = optics;
+            match pred(&self) {
+                true => self.over(optics, f),
+                false => self,
+            }
+        }
+    }
+
+    impl<P, Pm, Image: ?Sized, T: ?Sized> PrismRef<_filtered<P, Pm>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _filtered<P, Pm>) -> Option<&Image> {
+            let _filtered(optics, mut pred) = optics;
+            match pred(self) {
+                true => self.preview_ref(optics),
+                false => None,
+            }
+        }
+    }
+
+    impl<P, Pm, Image: ?Sized, T: ?Sized> TryPrismRef<_filtered<P, Pm>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _filtered<P, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            let _filtered(optics, mut pred) = optics;
+            match pred(self) {
+                true => self.miss_ref(optics, path),
+                false => no_focus(path, "_filtered"),
+            }
+        }
+    }
+
+    impl<P, Pm, Image: ?Sized, T: ?Sized> PrismMut<_filtered<P, Pm>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: PrismMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: _filtered<P, Pm>) -> Option<&mut Image> {
+            let _filtered(optics, mut pred) = optics;
+            if pred(self) {
+                self.preview_mut(optics)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl<P, Pm, Image, T> Prism<_filtered<P, Pm>, Image> for T
+    where
+        P: FnMut(&T) -> bool,
+        T: Prism<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _filtered<P, Pm>) -> Option<Image> {
+            let _filtered(optics, mut pred) = optics;
+            match pred(&self) {
+                true => self.preview(optics),
+                false => None,
+            }
+        }
+    }
+
+    macro_rules! impl_find_mut {
+        (<$item:ident $(, const $c:ident: $ct:ty)?> $collector:ty) => {
+            impl<P, Tr, Image: ?Sized, $item $(, const $c: $ct)?> TraversalRef<_find<P, Tr>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: _find<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let _find(optics, mut pred) = optics;
+                    match self.iter().find(|x| pred(x)) {
+                        Some(x) => x.try_for_each_ref(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P, Tr, Image: ?Sized, $item $(, const $c: $ct)?> IndexedTraversalRef<_find<P, Tr>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: _find<P, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let _find(optics, mut pred) = optics;
+                    match self.iter().enumerate().find(|(_, x)| pred(x)) {
+                        Some((i, x)) => {
+                            path.push(Segment::Index(i));
+                            let flow = x.try_ifor_each_ref(optics, path, f);
+                            path.pop();
+                            flow
+                        }
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P, Tr, Image: ?Sized, $item $(, const $c: $ct)?> TraversalMut<_find<P, Tr>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _find<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let _find(optics, mut pred) = optics;
+                    match self.iter_mut().find(|x| pred(x)) {
+                        Some(x) => x.try_for_each_mut(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P, Tr, Image: ?Sized, $item $(, const $c: $ct)?> IndexedTraversalMut<_find<P, Tr>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _find<P, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let _find(optics, mut pred) = optics;
+                    if let Some((i, x)) = self.iter_mut().enumerate().find(|(_, x)| pred(x)) {
+                        path.push(Segment::Index(i));
+                        let flow = x.try_ifor_each_mut(optics, path, f);
+                        path.pop();
+                        flow
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
+            }
+
+            impl<P, Pm, Image: ?Sized, $item $(, const $c: $ct)?> PrismRef<_find<P, Pm>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: _find<P, Pm>) -> Option<&Image> {
+                    let _find(optics, mut pred) = optics;
+                    self.iter().find(|x| pred(x))?.preview_ref(optics)
+                }
+            }
+
+            impl<P, Pm, Image: ?Sized, $item $(, const $c: $ct)?> TryPrismRef<_find<P, Pm>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: _find<P, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    let _find(optics, mut pred) = optics;
+                    match self.iter().enumerate().find(|(_, x)| pred(x)) {
+                        Some((i, x)) => {
+                            path.push(Segment::Index(i));
+                            let miss = x.miss_ref(optics, path);
+                            path.pop();
+                            miss
+                        }
+                        None => no_focus(path, "_find"),
+                    }
+                }
+            }
+
+            impl<P, Pm, Image: ?Sized, $item $(, const $c: $ct)?> PrismMut<_find<P, Pm>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: _find<P, Pm>) -> Option<&mut Image> {
+                    let _find(optics, mut pred) = optics;
+                    self.iter_mut().find(|x| pred(x))?.preview_mut(optics)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_find {
+        (<$item:ident $(, const $c:ident: $ct:ty)?> $collector:ty, |$this:ident, $g:ident| $rebuild:expr) => {
+            impl_find_mut!(<$item $(, const $c: $ct)?> $collector);
+
+            impl<P, Tr, Image, $item $(, const $c: $ct)?> Traversal<_find<P, Tr>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: _find<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let _find(optics, mut pred) = optics;
+                    match IntoIterator::into_iter(self).find(|x| pred(x)) {
+                        Some(x) => x.try_for_each_move(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, optics: _find<P, Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let _find(optic, mut pred) = optics;
+                    let mut optic = Some(optic);
+                    let $g = |x: $item| match optic.take() {
+                        Some(tr) if pred(&x) => x.over(tr, &mut f),
+                        tr => {
+                            optic = tr;
+                            x
+                        }
+                    };
+                    let $this = self;
+                    $rebuild
+                }
+            }
+
+            impl<P, Pm, Image, $item $(, const $c: $ct)?> Prism<_find<P, Pm>, Image> for $collector
+            where
+                P: FnMut(&$item) -> bool,
+                $item: Prism<Pm, Image>,
+            {
+                #[inline]
+                fn preview(self, optics: _find<P, Pm>) -> Option<Image> {
+                    let _find(optics, mut pred) = optics;
+                    IntoIterator::into_iter(self).find(|x| pred(x))?.preview(optics)
+                }
+            }
+        };
+    }
+
+    impl_find!(<T> Vec<T>, |this, g| this.into_iter().map(g).collect());
+    impl_find!(<T> VecDeque<T>, |this, g| this.into_iter().map(g).collect());
+    impl_find!(<T> LinkedList<T>, |this, g| this.into_iter().map(g).collect());
+    impl_find!(<T, const N: usize> [T; N], |this, g| this.map(g));
+    impl_find!(<T> Box<[T]>, |this, g| this.into_vec().into_iter().map(g).collect());
+    impl_find_mut!(<T> [T]);
+
+    // the image of `_only` and `_nearly` is the unit,
+    // and leaking a zero-sized `Box` doesn't allocate
+    pub(super) const UNIT: &() = &();
+
+    pub(super) fn unit_mut() -> &'static mut () {
+        Box::leak(Box::new(()))
+    }
+
+    macro_rules! impl_unit_prism {
+        ($name:literal, <$($param:ident),*> $optic:ty, [$($bound:tt)*], |$this:ident, $optics:ident| $matches:expr, $value:tt) => {
+            impl<T, Image: ?Sized, Tr, $($param),*> TraversalRef<$optic, Image> for T
+            where
+                (): TraversalRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $this = self;
+                    match $matches {
+                        true => UNIT.try_for_each_ref($optics.0, f),
+                        false => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> IndexedTraversalRef<$optic, Image> for T
+            where
+                (): TraversalRef<Tr, Image>,
+                $($bound)*
+            {
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> TraversalMut<$optic, Image> for T
+            where
+                (): TraversalMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $this = &*self;
+                    if $matches {
+                        unit_mut().try_for_each_mut($optics.0, f)
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> IndexedTraversalMut<$optic, Image> for T
+            where
+                (): TraversalMut<Tr, Image>,
+                $($bound)*
+            {
+            }
+
+            impl<T, Image, Tr, $($param),*> Traversal<$optic, Image> for T
+            where
+                (): Traversal<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, $optics: $optic, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let $this = &self;
+                    if $matches {
+                        ().try_for_each_move($optics.0, f)
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, $optics: $optic, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let $this = &self;
+                    if $matches {
+                        ().over($optics.0, f)
+                    }
+                    self
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> PrismRef<$optic, Image> for T
+            where
+                (): PrismRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview_ref(&self, $optics: $optic) -> Option<&Image> {
+                    let $this = self;
+                    match $matches {
+                        true => UNIT.preview_ref($optics.0),
+                        false => None,
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> TryPrismRef<$optic, Image> for T
+            where
+                (): TryPrismRef<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn miss_ref(&self, $optics: $optic, path: &mut OpticPath) -> Option<PathMiss> {
+                    let $this = self;
+                    match $matches {
+                        true => UNIT.miss_ref($optics.0, path),
+                        false => no_focus(path, $name),
+                    }
+                }
+            }
+
+            impl<T, Image: ?Sized, Tr, $($param),*> PrismMut<$optic, Image> for T
+            where
+                (): PrismMut<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview_mut(&mut self, $optics: $optic) -> Option<&mut Image> {
+                    let $this = &*self;
+                    match $matches {
+                        true => unit_mut().preview_mut($optics.0),
+                        false => None,
+                    }
+                }
+            }
+
+            impl<T, Image, Tr, $($param),*> Prism<$optic, Image> for T
+            where
+                (): Prism<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn preview(self, $optics: $optic) -> Option<Image> {
+                    let $this = &self;
+                    match $matches {
+                        true => ().preview($optics.0),
+                        false => None,
+                    }
+                }
+            }
+
+            impl<T, Image, Tr, $($param),*> Review<$optic, Image> for T
+            where
+                (): Review<Tr, Image>,
+                $($bound)*
+            {
+                #[inline]
+                fn review($optics: $optic, from: Image) -> Self {
+                    <() as Review<Tr, Image>>::review($optics.0, from);
+                    $optics.$value
+                }
+            }
+        };
+    }
+
+    impl_unit_prism!("_only", <> _only<T, Tr>, [T: PartialEq,], |this, optics| *this == optics.1, 1);
+    impl_unit_prism!("_nearly", <P> _nearly<T, P, Tr>, [P: Fn(&T) -> bool,], |this, optics| {
+        (optics.2)(this)
+    }, 1);
+}
+
+mod impl_fn {
+    /***********************************************************
+     * optics built from closures
+     * the structs are braced so that the constructors can share their names
+     * and infer the signatures of the closures for `optics!`,
+     * and the focus must be `'static` since it can't be bounded by the borrow of the source
+     ************************************************************/
+    use super::impl_ix::no_focus;
+    use crate::path::{OpticPath, PathMiss};
+    use crate::*;
+    use std::convert::Infallible;
+    use std::fmt::{self, Debug, Formatter};
+    use std::marker::PhantomData;
+    use std::ops::ControlFlow;
+
+    /// a lens from the closures returning the references, implemented `LensMut`
+    ///
+    /// ```ignore
+    /// let optics = optics!(lens_fn(|x: &Foo| &x.a, |x: &mut Foo| &mut x.a).Some);
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    #[allow(non_camel_case_types)]
+    pub struct lens_fn<Get, GetMut, Optic> {
+        pub optic: Optic,
+        pub get: Get,
+        pub get_mut: GetMut,
+    }
+
+    pub fn lens_fn<S, A, Get, GetMut, Optic>(
+        optic: Optic,
+        get: Get,
+        get_mut: GetMut,
+    ) -> lens_fn<Get, GetMut, Optic>
+    where
+        S: ?Sized,
+        A: ?Sized + 'static,
+        Get: Fn(&S) -> &A,
+        GetMut: Fn(&mut S) -> &mut A,
+    {
+        lens_fn {
+            optic,
+            get,
+            get_mut,
+        }
+    }
+
+    /// a prism from the closures previewing the references and the one constructing the source,
+    /// implemented `PrismMut` and `Review`
+    ///
+    /// ```ignore
+    /// let optics = optics!(prism_fn(
+    ///     |x: &Shape| match x { Circle(r) => Some(r), _ => None },
+    ///     |x: &mut Shape| match x { Circle(r) => Some(r), _ => None },
+    ///     Circle,
+    /// ));
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    #[allow(non_camel_case_types)]
+    pub struct prism_fn<Preview, PreviewMut, Rev, Optic> {
+        pub optic: Optic,
+        pub preview: Preview,
+        pub preview_mut: PreviewMut,
+        pub review: Rev,
+    }
+
+    pub fn prism_fn<S, A: 'static, Preview, PreviewMut, Rev, Optic>(
+        optic: Optic,
+        preview: Preview,
+        preview_mut: PreviewMut,
+        review: Rev,
+    ) -> prism_fn<Preview, PreviewMut, Rev, Optic>
+    where
+        Preview: Fn(&S) -> Option<&A>,
+        PreviewMut: Fn(&mut S) -> Option<&mut A>,
+        Rev: Fn(A) -> S,
+    {
+        prism_fn {
+            optic,
+            preview,
+            preview_mut,
+            review,
+        }
+    }
+
+    /// a read-only lens from the closure returning the reference, implemented `LensRef`
+    ///
+    /// The closure should borrow from the source, and the type of the focus should be `'static`.
+    /// Use `_to_value` for the closures computing a value.
+    ///
+    /// ```ignore
+    /// assert_eq!(x.view_ref(optics!(_to(|s: &String| s.as_str()))), "...");
+    /// ```
+    #[derive(Copy, Clone, Debug)]
+    #[allow(non_camel_case_types)]
+    pub struct _to<Get, Optic> {
+        pub optic: Optic,
+        pub get: Get,
+    }
+
+    pub fn _to<S, A, Get, Optic>(optic: Optic, get: Get) -> _to<Get, Optic>
+    where
+        S: ?Sized,
+        A: ?Sized + 'static,
+        Get: Fn(&S) -> &A,
+    {
+        _to { optic, get }
+    }
+
+    /// a read-only getter from the closure computing a value,
+    /// which is a temporary, so it goes on with `with_view_ref` and `with_each_ref` like the locks
+    ///
+    /// ```ignore
+    /// assert_eq!(x.with_view_ref(optics!(_1._to_value(|v: &Vec<i32>| v.len())), |n| *n), 3);
+    /// ```
+    #[allow(non_camel_case_types)]
+    pub struct _to_value<S: ?Sized, Get, Optic> {
+        pub optic: Optic,
+        pub get: Get,
+        pub source: PhantomData<fn(&S)>,
+    }
+
+    pub fn _to_value<S, A, Get, Optic>(optic: Optic, get: Get) -> _to_value<S, Get, Optic>
+    where
+        S: ?Sized,
+        Get: Fn(&S) -> A,
+    {
+        _to_value {
+            optic,
+            get,
+            source: PhantomData,
+        }
+    }
+
+    // not derived, the source needn't be `Clone` or `Debug`
+    impl<S: ?Sized, Get: Clone, Optic: Clone> Clone for _to_value<S, Get, Optic> {
+        fn clone(&self) -> Self {
+            _to_value {
+                optic: self.optic.clone(),
+                get: self.get.clone(),
+                source: PhantomData,
+            }
+        }
+    }
+
+    impl<S: ?Sized, Get: Copy, Optic: Copy> Copy for _to_value<S, Get, Optic> {}
+
+    impl<S: ?Sized, Get, Optic: Debug> Debug for _to_value<S, Get, Optic> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_struct("_to_value").field("optic", &self.optic).finish()
+        }
+    }
+
+    macro_rules! impl_get_ref {
+        ($optic:ident<$($param:ident),*>, $Get:ident, $get:ident) => {
+            impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, $($param),*> TraversalRef<$optic<$($param,)* Tr>, Image> for S
+            where
+                $Get: Fn(&S) -> &A,
+                A: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (optics.$get)(self).try_for_each_ref(optics.optic, f)
+                }
+            }
+
+            impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, $($param),*> IndexedTraversalRef<$optic<$($param,)* Tr>, Image> for S
+            where
+                $Get: Fn(&S) -> &A,
+                A: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<$($param,)* Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    (optics.$get)(self).try_ifor_each_ref(optics.optic, path, f)
+                }
+            }
+
+            impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, $($param),*> PrismRef<$optic<$($param,)* Pm>, Image> for S
+            where
+                $Get: Fn(&S) -> &A,
+                A: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: $optic<$($param,)* Pm>) -> Option<&Image> {
+                    (optics.$get)(self).preview_ref(optics.optic)
+                }
+            }
+
+            impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, $($param),*> TryPrismRef<$optic<$($param,)* Pm>, Image> for S
+            where
+                $Get: Fn(&S) -> &A,
+                A: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: $optic<$($param,)* Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    (optics.$get)(self).miss_ref(optics.optic, path)
+                }
+            }
+
+            impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Ls, $($param),*> LensRef<$optic<$($param,)* Ls>, Image> for S
+            where
+                $Get: Fn(&S) -> &A,
+                A: LensRef<Ls, Image>,
+            {
+                #[inline]
+                fn view_ref(&self, optics: $optic<$($param,)* Ls>) -> &Image {
+                    (optics.$get)(self).view_ref(optics.optic)
+                }
+            }
+        };
+    }
+
+    impl_get_ref!(lens_fn<Get, GetMut>, Get, get);
+    impl_get_ref!(_to<Get>, Get, get);
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Get, GetMut> TraversalMut<lens_fn<Get, GetMut, Tr>, Image>
+        for S
+    where
+        Get: Fn(&S) -> &A,
+        GetMut: Fn(&mut S) -> &mut A,
+        A: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: lens_fn<Get, GetMut, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            (optics.get_mut)(self).try_for_each_mut(optics.optic, f)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Get, GetMut> IndexedTraversalMut<lens_fn<Get, GetMut, Tr>, Image>
+        for S
+    where
+        Get: Fn(&S) -> &A,
+        GetMut: Fn(&mut S) -> &mut A,
+        A: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: lens_fn<Get, GetMut, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            (optics.get_mut)(self).try_ifor_each_mut(optics.optic, path, f)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, Get, GetMut> PrismMut<lens_fn<Get, GetMut, Pm>, Image>
+        for S
+    where
+        Get: Fn(&S) -> &A,
+        GetMut: Fn(&mut S) -> &mut A,
+        A: PrismMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: lens_fn<Get, GetMut, Pm>) -> Option<&mut Image> {
+            (optics.get_mut)(self).preview_mut(optics.optic)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Ls, Get, GetMut> LensMut<lens_fn<Get, GetMut, Ls>, Image>
+        for S
+    where
+        Get: Fn(&S) -> &A,
+        GetMut: Fn(&mut S) -> &mut A,
+        A: LensMut<Ls, Image>,
+    {
+        #[inline]
+        fn view_mut(&mut self, optics: lens_fn<Get, GetMut, Ls>) -> &mut Image {
+            (optics.get_mut)(self).view_mut(optics.optic)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Pre, PreMut, Rev>
+        TraversalRef<prism_fn<Pre, PreMut, Rev, Tr>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        A: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(
+            &'a self,
+            optics: prism_fn<Pre, PreMut, Rev, Tr>,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match (optics.preview)(self) {
+                Some(x) => x.try_for_each_ref(optics.optic, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Pre, PreMut, Rev>
+        IndexedTraversalRef<prism_fn<Pre, PreMut, Rev, Tr>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        A: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: prism_fn<Pre, PreMut, Rev, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match (optics.preview)(self) {
+                Some(x) => x.try_ifor_each_ref(optics.optic, path, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Pre, PreMut, Rev>
+        TraversalMut<prism_fn<Pre, PreMut, Rev, Tr>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        PreMut: Fn(&mut S) -> Option<&mut A>,
+        A: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: prism_fn<Pre, PreMut, Rev, Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match (optics.preview_mut)(self) {
+                Some(x) => x.try_for_each_mut(optics.optic, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Tr, Pre, PreMut, Rev>
+        IndexedTraversalMut<prism_fn<Pre, PreMut, Rev, Tr>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        PreMut: Fn(&mut S) -> Option<&mut A>,
+        A: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: prism_fn<Pre, PreMut, Rev, Tr>,
+            path: &mut OpticPath,
+            f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match (optics.preview_mut)(self) {
+                Some(x) => x.try_ifor_each_mut(optics.optic, path, f),
+                None => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, Pre, PreMut, Rev>
+        PrismRef<prism_fn<Pre, PreMut, Rev, Pm>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        A: PrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: prism_fn<Pre, PreMut, Rev, Pm>) -> Option<&Image> {
+            (optics.preview)(self)?.preview_ref(optics.optic)
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, Pre, PreMut, Rev>
+        TryPrismRef<prism_fn<Pre, PreMut, Rev, Pm>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        A: TryPrismRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(
+            &self,
+            optics: prism_fn<Pre, PreMut, Rev, Pm>,
+            path: &mut OpticPath,
+        ) -> Option<PathMiss> {
+            match (optics.preview)(self) {
+                Some(x) => x.miss_ref(optics.optic, path),
+                None => no_focus(path, "prism_fn"),
+            }
+        }
+    }
+
+    impl<S: ?Sized, A: ?Sized + 'static, Image: ?Sized, Pm, Pre, PreMut, Rev>
+        PrismMut<prism_fn<Pre, PreMut, Rev, Pm>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        PreMut: Fn(&mut S) -> Option<&mut A>,
+        A: PrismMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: prism_fn<Pre, PreMut, Rev, Pm>) -> Option<&mut Image> {
+            (optics.preview_mut)(self)?.preview_mut(optics.optic)
+        }
+    }
+
+    impl<S, A: 'static, Image, Rv, Pre, PreMut, Rev> Review<prism_fn<Pre, PreMut, Rev, Rv>, Image> for S
+    where
+        Pre: Fn(&S) -> Option<&A>,
+        Rev: Fn(A) -> S,
+        A: Review<Rv, Image>,
+    {
+        #[inline]
+        fn review(optics: prism_fn<Pre, PreMut, Rev, Rv>, from: Image) -> Self {
+            (optics.review)(A::review(optics.optic, from))
+        }
+    }
+
+    // the optics built from closures go on before a lock like the generated optics
+    impl<Get, GetMut, Optic: SplitAtLock> SplitAtLock for lens_fn<Get, GetMut, Optic> {
+        type Prefix = lens_fn<Get, GetMut, Optic::Prefix>;
+        type Rest = Optic::Rest;
+
+        #[inline]
+        fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+            let lens_fn { optic, get, get_mut } = self;
+            let (prefix, rest) = optic.split_at_lock();
+            (lens_fn { optic: prefix, get, get_mut }, rest)
+        }
+    }
+
+    impl<Pre, PreMut, Rev, Optic: SplitAtLock> SplitAtLock for prism_fn<Pre, PreMut, Rev, Optic> {
+        type Prefix = prism_fn<Pre, PreMut, Rev, Optic::Prefix>;
+        type Rest = Optic::Rest;
+
+        #[inline]
+        fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+            let prism_fn { optic, preview, preview_mut, review } = self;
+            let (prefix, rest) = optic.split_at_lock();
+            (prism_fn { optic: prefix, preview, preview_mut, review }, rest)
+        }
+    }
+
+    impl<Get, Optic: SplitAtLock> SplitAtLock for _to<Get, Optic> {
+        type Prefix = _to<Get, Optic::Prefix>;
+        type Rest = Optic::Rest;
+
+        #[inline]
+        fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+            let _to { optic, get } = self;
+            let (prefix, rest) = optic.split_at_lock();
+            (_to { optic: prefix, get }, rest)
+        }
+    }
+
+    // `_to_value` splits the optics like a lock, the optics before it reach the source,
+    // and it goes on from the source with the computed value.
+    // there is nothing to mutate, so the mutable focus is uninhabited
+    impl<S: ?Sized, Get, Optic> SplitAtLock for _to_value<S, Get, Optic> {
+        type Prefix = __;
+        type Rest = Self;
+
+        #[inline]
+        fn split_at_lock(self) -> (__, Self) {
+            (__, self)
+        }
+    }
+
+    // the optics built from closures end the static path
+    macro_rules! impl_split_static_path {
+        ($($optic:ident<$($param:ident),*>),*) => {
+            $(
+                impl<$($param,)* Optic> SplitStaticPath for $optic<$($param,)* Optic> {
+                    type Prefix = __;
+                    type Rest = Self;
+
+                    #[inline]
+                    fn split_static_path(self) -> (__, Self) {
+                        (__, self)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_split_static_path!(lens_fn<Get, GetMut>, prism_fn<Pre, PreMut, Rev>, _to<Get>);
+
+    impl<S: ?Sized, Get, Optic> SplitStaticPath for _to_value<S, Get, Optic> {
+        type Prefix = __;
+        type Rest = Self;
+
+        #[inline]
+        fn split_static_path(self) -> (__, Self) {
+            (__, self)
+        }
+    }
+
+    impl<S: ?Sized, A, Get, Tr, Image: ?Sized> Resume<Image, ByTraversal> for _to_value<S, Get, Tr>
+    where
+        Get: Fn(&S) -> A,
+        A: ScopedTraversalRef<Tr, Image>,
+    {
+        type FocusRef = S;
+        type FocusMut = Infallible;
+
+        #[inline]
+        fn resume_ref(self, focus: &S, f: &mut dyn FnMut(&Image)) {
+            (self.get)(focus).with_each_ref(self.optic, f)
+        }
+
+        #[inline]
+        fn resume_mut(self, focus: &mut Infallible, _f: &mut dyn FnMut(&mut Image)) {
+            match *focus {}
+        }
+    }
+
+    impl<S: ?Sized, A, Get, Ls, Image: ?Sized> Resume<Image, ByLens> for _to_value<S, Get, Ls>
+    where
+        Get: Fn(&S) -> A,
+        A: ScopedLensRef<Ls, Image>,
+    {
+        type FocusRef = S;
+        type FocusMut = Infallible;
+
+        #[inline]
+        fn resume_ref(self, focus: &S, f: &mut dyn FnMut(&Image)) {
+            (self.get)(focus).with_view_ref(self.optic, f)
+        }
+
+        #[inline]
+        fn resume_mut(self, focus: &mut Infallible, _f: &mut dyn FnMut(&mut Image)) {
+            match *focus {}
+        }
+    }
+}
+
+mod impl_modifier {
+    /***********************************************************
+     * impl for _taking, _dropping, _backwards and _element
+     * which modify the order or the number of the foci of the rest optics
+     ************************************************************/
+    use super::impl_ix::no_focus;
+    use crate::path::{OpticPath, PathMiss};
+    use crate::*;
+    use std::ops::ControlFlow;
+
+    // stop the traversal early without the `B` of the caller
+    fn early<B>(flow: ControlFlow<Option<B>>) -> ControlFlow<B> {
+        match flow {
+            ControlFlow::Break(Some(b)) => ControlFlow::Break(b),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
+    // count the foci of the rest optics to visit the `n` from the `skip`th at most,
+    // breaking the rest optics right after the last one
+    struct Slice {
+        skip: usize,
+        n: usize,
+        i: usize,
+    }
+
+    impl Slice {
+        fn new((skip, n): (usize, usize)) -> Self {
+            Slice { skip, n, i: 0 }
+        }
+
+        fn visit<X, B>(&mut self, x: X, f: impl FnOnce(X) -> ControlFlow<B>) -> ControlFlow<Option<B>> {
+            self.i += 1;
+            if self.i <= self.skip {
+                return ControlFlow::Continue(());
+            }
+            if self.i - self.skip > self.n {
+                return ControlFlow::Break(None);
+            }
+            if let ControlFlow::Break(b) = f(x) {
+                return ControlFlow::Break(Some(b));
+            }
+            if self.i - self.skip == self.n {
+                ControlFlow::Break(None)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    // visit the `n` foci from the `skip`th at most
+    macro_rules! impl_slice {
+        ($optic:ident, |$optics:ident| ($skip:expr, $n:expr)) => {
+            impl<Tr, Image: ?Sized, T: ?Sized> TraversalRef<$optic<Tr>, Image> for T
+            where
+                T: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, $optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let mut slice = Slice::new(($skip, $n));
+                    early(self.try_for_each_ref($optics.0, |x| slice.visit(x, &mut f)))
+                }
+            }
+
+            impl<Tr, Image: ?Sized, T: ?Sized> IndexedTraversalRef<$optic<Tr>, Image> for T
+            where
+                T: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    $optics: $optic<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let mut slice = Slice::new(($skip, $n));
+                    early(self.try_ifor_each_ref($optics.0, path, |path, x| slice.visit(x, |x| f(path, x))))
+                }
+            }
+
+            impl<Tr, Image: ?Sized, T: ?Sized> TraversalMut<$optic<Tr>, Image> for T
+            where
+                T: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, $optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let mut slice = Slice::new(($skip, $n));
+                    early(self.try_for_each_mut($optics.0, |x| slice.visit(x, &mut f)))
+                }
+            }
+
+            impl<Tr, Image: ?Sized, T: ?Sized> IndexedTraversalMut<$optic<Tr>, Image> for T
+            where
+                T: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(
+                    &'a mut self,
+                    $optics: $optic<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let mut slice = Slice::new(($skip, $n));
+                    early(self.try_ifor_each_mut($optics.0, path, |path, x| slice.visit(x, |x| f(path, x))))
+                }
+            }
+
+            // `over` has to rebuild the whole structure, so it goes through every focus
+            impl<Tr, Image, T> Traversal<$optic<Tr>, Image> for T
+            where
+                T: Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, $optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let mut slice = Slice::new(($skip, $n));
+                    early(self.try_for_each_move($optics.0, |x| slice.visit(x, &mut f)))
+                }
+
+                #[inline]
+                fn over<F>(self, $optics: $optic<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let (skip, n): (usize, usize) = ($skip, $n);
+                    let mut i = 0;
+                    self.over($optics.0, |x| {
+                        let x = if i >= skip && i - skip < n { f(x) } else { x };
+                        i += 1;
+                        x
+                    })
+                }
+            }
+        };
+    }
+
+    impl_slice!(_taking, |optics| (0, optics.1));
+    impl_slice!(_dropping, |optics| (optics.1, usize::MAX));
+    impl_slice!(_element, |optics| (optics.1, 1));
+
+    impl<Pm, Image: ?Sized, T: ?Sized> PrismRef<_element<Pm>, Image> for T
+    where
+        T: TraversalRef<Pm, Image>,
+    {
+        #[inline]
+        fn preview_ref(&self, optics: _element<Pm>) -> Option<&Image> {
+            // call the traversal of `_element` itself rather than the one of the rest optics
+            let flow = <T as TraversalRef<_element<Pm>, Image>>::try_for_each_ref(
+                self,
+                optics,
+                ControlFlow::Break,
+            );
+            match flow {
+                ControlFlow::Break(x) => Some(x),
+                ControlFlow::Continue(()) => None,
+            }
+        }
+    }
+
+    impl<Pm, Image: ?Sized, T: ?Sized> TryPrismRef<_element<Pm>, Image> for T
+    where
+        T: TraversalRef<Pm, Image>,
+    {
+        #[inline]
+        fn miss_ref(&self, optics: _element<Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+            match PrismRef::<_element<Pm>, Image>::preview_ref(self, optics) {
+                Some(_) => None,
+                None => no_focus(path, "_element"),
+            }
+        }
+    }
+
+    impl<Pm, Image: ?Sized, T: ?Sized> PrismMut<_element<Pm>, Image> for T
+    where
+        T: TraversalMut<Pm, Image>,
+    {
+        #[inline]
+        fn preview_mut(&mut self, optics: _element<Pm>) -> Option<&mut Image> {
+            let flow = <T as TraversalMut<_element<Pm>, Image>>::try_for_each_mut(
+                self,
+                optics,
+                ControlFlow::Break,
+            );
+            match flow {
+                ControlFlow::Break(x) => Some(x),
+                ControlFlow::Continue(()) => None,
+            }
+        }
+    }
+
+    impl<Pm, Image, T> Prism<_element<Pm>, Image> for T
+    where
+        T: Traversal<Pm, Image>,
+    {
+        #[inline]
+        fn preview(self, optics: _element<Pm>) -> Option<Image> {
+            let flow = <T as Traversal<_element<Pm>, Image>>::try_for_each_move(
+                self,
+                optics,
+                ControlFlow::Break,
+            );
+            match flow {
+                ControlFlow::Break(x) => Some(x),
+                ControlFlow::Continue(()) => None,
+            }
+        }
+    }
+
+    impl<Tr, Image: ?Sized, T: ?Sized> TraversalRef<_backwards<Tr>, Image> for T
+    where
+        T: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _backwards<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let mut foci = vec![];
+            self.for_each_ref(optics.0, |x| foci.push(x));
+            foci.into_iter().rev().try_for_each(f)
+        }
+    }
+
+    impl<Tr, Image: ?Sized, T: ?Sized> IndexedTraversalRef<_backwards<Tr>, Image> for T
+    where
+        T: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _backwards<Tr>,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let mut foci = vec![];
+            let _ = self.try_ifor_each_ref(optics.0, path, |path, x| -> ControlFlow<()> {
+                foci.push((path.clone(), x));
+                ControlFlow::Continue(())
+            });
+            foci.into_iter().rev().try_for_each(|(path, x)| f(&path, x))
+        }
+    }
+
+    impl<Tr, Image: ?Sized, T: ?Sized> TraversalMut<_backwards<Tr>, Image> for T
+    where
+        T: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _backwards<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            self.traverse_mut(optics.0).into_iter().rev().try_for_each(f)
+        }
+    }
+
+    impl<Tr, Image: ?Sized, T: ?Sized> IndexedTraversalMut<_backwards<Tr>, Image> for T
+    where
+        T: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _backwards<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let mut foci = vec![];
+            self.ifor_each_mut(optics.0, path, |path, x| foci.push((path.clone(), x)));
+            foci.into_iter().rev().try_for_each(|(path, x)| f(&path, x))
+        }
+    }
+
+    // swap the foci of the rest optics end for end in place
+    fn reverse_foci<Tr, Image, T>(x: &mut T, optics: Tr)
+    where
+        T: TraversalMut<Tr, Image>,
+    {
+        let mut foci = x.traverse_mut(optics);
+        let n = foci.len();
+        for i in 0..n / 2 {
+            let (front, back) = foci.split_at_mut(n - 1 - i);
+            std::mem::swap(&mut *front[i], &mut *back[0]);
+        }
+    }
+
+    // `over` reverses the foci, maps them in order and reverses them back,
+    // so `f` visits them backwards without cloning them out
+    impl<Tr: Clone, Image, T> Traversal<_backwards<Tr>, Image> for T
+    where
+        T: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _backwards<Tr>, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.traverse(optics.0).into_iter().rev().try_for_each(f)
+        }
+
+        #[inline]
+        fn over<F>(mut self, optics: _backwards<Tr>, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            reverse_foci(&mut self, optics.0.clone());
+            let mut this = self.over(optics.0.clone(), f);
+            reverse_foci(&mut this, optics.0);
+            this
+        }
+    }
+}
+
+mod impl_text {
+    /***********************************************************
+     * impl for _lines, _words, _char_strs, _chars, _prefixed and _suffixed
+     * a `str` doesn't own its pieces as `String` or `char`,
+     * so the foci are the `str` slices, which can be changed in place
+     * but not moved out, and a `String` is rebuilt from the owned pieces
+     * by `TraversalOwned` and `Review`.
+     ************************************************************/
+    use super::impl_ix::no_focus;
+    use crate::*;
+    use crate::path::{OpticPath, PathMiss, Segment};
+    use std::ops::{ControlFlow, Range};
+
+    // the byte ranges of the pieces borrowed from `s`
+    fn ranges<'a>(s: &'a str, pieces: impl Iterator<Item = &'a str>) -> Vec<Range<usize>> {
+        let base = s.as_ptr() as usize;
+        pieces
+            .map(|piece| {
+                let start = piece.as_ptr() as usize - base;
+                start..start + piece.len()
+            })
+            .collect()
+    }
+
+    // rebuild `s` with the pieces in the ascending `ranges` replaced by `f`
+    fn rebuild(s: &str, ranges: Vec<Range<usize>>, mut f: impl FnMut(&str) -> String) -> String {
+        let mut rebuilt = String::with_capacity(s.len());
+        let mut end = 0;
+        for range in ranges {
+            rebuilt.push_str(&s[end..range.start]);
+            rebuilt.push_str(&f(&s[range.clone()]));
+            end = range.end;
+        }
+        rebuilt.push_str(&s[end..]);
+        rebuilt
+    }
+
+    // split `s` into the disjoint pieces in the ascending `ranges`
+    fn split_mut(mut s: &mut str, ranges: Vec<Range<usize>>) -> Vec<&mut str> {
+        let mut offset = 0;
+        let mut pieces = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let (_, tail) = std::mem::take(&mut s).split_at_mut(range.start - offset);
+            let (piece, tail) = tail.split_at_mut(range.end - range.start);
+            pieces.push(piece);
+            s = tail;
+            offset = range.end;
+        }
+        pieces
+    }
+
+    macro_rules! impl_pieces {
+        ($optic:ident, |$s:ident| $pieces:expr) => {
+            impl<Tr: Clone, Image: ?Sized> TraversalRef<$optic<Tr>, Image> for str
+            where
+                str: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $s = self;
+                    $pieces.try_for_each(|piece| piece.try_for_each_ref(optics.0.clone(), &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized> IndexedTraversalRef<$optic<Tr>, Image> for str
+            where
+                str: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<Tr>,
+                    path: &mut OpticPath,
+                    mut f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $s = self;
+                    $pieces.enumerate().try_for_each(|(i, piece)| {
+                        path.push(Segment::Index(i));
+                        let flow = piece.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized> TraversalMut<$optic<Tr>, Image> for str
+            where
+                str: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ranges = {
+                        let $s = &*self;
+                        ranges($s, $pieces)
+                    };
+                    split_mut(self, ranges)
+                        .into_iter()
+                        .try_for_each(|piece| piece.try_for_each_mut(optics.0.clone(), &mut f))
+                }
+            }
+
+            impl<Tr: Clone, Image: ?Sized> IndexedTraversalMut<$optic<Tr>, Image> for str
+            where
+                str: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: $optic<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ranges = {
+                        let $s = &*self;
+                        ranges($s, $pieces)
+                    };
+                    split_mut(self, ranges).into_iter().enumerate().try_for_each(|(i, piece)| {
+                        path.push(Segment::Index(i));
+                        let flow = piece.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                        path.pop();
+                        flow
+                    })
+                }
+            }
+
+            impl_string!($optic<>);
+
+            impl<Tr: Clone, Image> TraversalOwned<$optic<Tr>, Image> for String
+            where
+                String: TraversalOwned<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_owned<B, F>(self, optics: $optic<Tr>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let $s = self.as_str();
+                    $pieces.try_for_each(|piece| piece.to_owned().try_for_each_owned(optics.0.clone(), &mut f))
+                }
+
+                #[inline]
+                fn over_owned<F>(self, optics: $optic<Tr>, mut f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let ranges = {
+                        let $s = self.as_str();
+                        ranges($s, $pieces)
+                    };
+                    rebuild(&self, ranges, |piece| piece.to_owned().over_owned(optics.0.clone(), &mut f))
+                }
+            }
+        };
+    }
+
+    // a `String` goes on as its `str`
+    macro_rules! impl_string {
+        ($optic:ident<$($param:ident),*>) => {
+            impl<$($param,)* Tr, Image: ?Sized> TraversalRef<$optic<$($param,)* Tr>, Image> for String
+            where
+                str: TraversalRef<$optic<$($param,)* Tr>, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.as_str().try_for_each_ref(optics, f)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized> IndexedTraversalRef<$optic<$($param,)* Tr>, Image> for String
+            where
+                str: IndexedTraversalRef<$optic<$($param,)* Tr>, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<$($param,)* Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.as_str().try_ifor_each_ref(optics, path, f)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized> TraversalMut<$optic<$($param,)* Tr>, Image> for String
+            where
+                str: TraversalMut<$optic<$($param,)* Tr>, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.as_mut_str().try_for_each_mut(optics, f)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized> IndexedTraversalMut<$optic<$($param,)* Tr>, Image> for String
+            where
+                str: IndexedTraversalMut<$optic<$($param,)* Tr>, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(
+                    &'a mut self,
+                    optics: $optic<$($param,)* Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    self.as_mut_str().try_ifor_each_mut(optics, path, f)
+                }
+            }
+        };
+    }
+
+    impl_pieces!(_lines, |s| s.lines());
+    impl_pieces!(_words, |s| s.split_whitespace());
+    impl_pieces!(_char_strs, |s| s.char_indices().map(move |(i, c)| &s[i..i + c.len_utf8()]));
+
+    // the `char`s are decoded out of the `str`, so they are only moved out
+    // and the `String` is rebuilt from the rewritten ones
+    impl<Tr: Clone, Image> TraversalOwned<_chars<Tr>, Image> for String
+    where
+        char: TraversalOwned<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_owned<B, F>(self, optics: _chars<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            self.chars().try_for_each(|c| c.try_for_each_owned(optics.0.clone(), &mut f))
+        }
+
+        #[inline]
+        fn over_owned<F>(self, optics: _chars<Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            self.chars().map(|c| c.over_owned(optics.0.clone(), &mut f)).collect()
+        }
+    }
+
+    macro_rules! impl_affix {
+        (
+            $optic:ident,
+            |$this:ident, $affix:ident| $strip:expr,
+            |$this_mut:ident, $affix_mut:ident| $strip_mut:expr,
+            |$rest:ident, $affix_rv:ident| $review:expr
+        ) => {
+            impl<P: AsRef<str>, Tr, Image: ?Sized> TraversalRef<$optic<P, Tr>, Image> for str
+            where
+                str: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($this, $affix) = (self, optics.1.as_ref());
+                    match $strip {
+                        Some(rest) => rest.try_for_each_ref(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P: AsRef<str>, Tr, Image: ?Sized> IndexedTraversalRef<$optic<P, Tr>, Image> for str
+            where
+                str: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<P, Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($this, $affix) = (self, optics.1.as_ref());
+                    match $strip {
+                        Some(rest) => rest.try_ifor_each_ref(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P: AsRef<str>, Tr, Image: ?Sized> TraversalMut<$optic<P, Tr>, Image> for str
+            where
+                str: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: $optic<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($this_mut, $affix_mut) = (self, optics.1.as_ref());
+                    match $strip_mut {
+                        Some(rest) => rest.try_for_each_mut(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P: AsRef<str>, Tr, Image: ?Sized> IndexedTraversalMut<$optic<P, Tr>, Image> for str
+            where
+                str: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: $optic<P, Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let ($this_mut, $affix_mut) = (self, optics.1.as_ref());
+                    match $strip_mut {
+                        Some(rest) => rest.try_ifor_each_mut(optics.0, path, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> PrismRef<$optic<P, Pm>, Image> for str
+            where
+                str: PrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: $optic<P, Pm>) -> Option<&Image> {
+                    let ($this, $affix) = (self, optics.1.as_ref());
+                    $strip?.preview_ref(optics.0)
+                }
+            }
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> TryPrismRef<$optic<P, Pm>, Image> for str
+            where
+                str: TryPrismRef<Pm, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: $optic<P, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    let ($this, $affix) = (self, optics.1.as_ref());
+                    match $strip {
+                        Some(rest) => rest.miss_ref(optics.0, path),
+                        None => no_focus(path, stringify!($optic)),
+                    }
+                }
+            }
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> PrismMut<$optic<P, Pm>, Image> for str
+            where
+                str: PrismMut<Pm, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: $optic<P, Pm>) -> Option<&mut Image> {
+                    let ($this_mut, $affix_mut) = (self, optics.1.as_ref());
+                    $strip_mut?.preview_mut(optics.0)
+                }
+            }
+
+            impl_string!($optic<P>);
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> PrismRef<$optic<P, Pm>, Image> for String
+            where
+                str: PrismRef<$optic<P, Pm>, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: $optic<P, Pm>) -> Option<&Image> {
+                    self.as_str().preview_ref(optics)
+                }
+            }
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> TryPrismRef<$optic<P, Pm>, Image> for String
+            where
+                str: TryPrismRef<$optic<P, Pm>, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: $optic<P, Pm>, path: &mut OpticPath) -> Option<PathMiss> {
+                    self.as_str().miss_ref(optics, path)
+                }
+            }
+
+            impl<P: AsRef<str>, Pm, Image: ?Sized> PrismMut<$optic<P, Pm>, Image> for String
+            where
+                str: PrismMut<$optic<P, Pm>, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: $optic<P, Pm>) -> Option<&mut Image> {
+                    self.as_mut_str().preview_mut(optics)
+                }
+            }
+
+            impl<P: AsRef<str>, Tr, Image> TraversalOwned<$optic<P, Tr>, Image> for String
+            where
+                String: TraversalOwned<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_owned<B, F>(self, optics: $optic<P, Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let ($this, $affix) = (self.as_str(), optics.1.as_ref());
+                    match $strip {
+                        Some(rest) => rest.to_owned().try_for_each_owned(optics.0, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over_owned<F>(self, optics: $optic<P, Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let ($this, $affix) = (self.as_str(), optics.1.as_ref());
+                    let rest = match $strip {
+                        Some(rest) => rest.to_owned(),
+                        None => return self,
+                    };
+                    let ($rest, $affix_rv) = (rest.over_owned(optics.0, f), optics.1.as_ref());
+                    $review
+                }
+            }
+
+            impl<P: AsRef<str>, Rv, Image> Review<$optic<P, Rv>, Image> for String
+            where
+                String: Review<Rv, Image>,
+            {
+                #[inline]
+                fn review(optics: $optic<P, Rv>, from: Image) -> Self {
+                    let ($rest, $affix_rv) = (String::review(optics.0, from), optics.1.as_ref());
+                    $review
+                }
+            }
+        };
+    }
+
+    impl_affix!(
+        _prefixed,
+        |this, prefix| this.strip_prefix(prefix),
+        |this, prefix| this.starts_with(prefix).then(move || this.split_at_mut(prefix.len()).1),
+        |rest, prefix| prefix.to_string() + &rest
+    );
+    impl_affix!(
+        _suffixed,
+        |this, suffix| this.strip_suffix(suffix),
+        |this, suffix| {
+            let len = this.len().wrapping_sub(suffix.len());
+            this.ends_with(suffix).then(move || this.split_at_mut(len).0)
+        },
+        |rest, suffix| rest + suffix
+    );
+}
+
+mod impl_lock {
+    /***********************************************************
+     * the lock optics going on behind RefCell, Mutex and RwLock
+     * with the traits in `crate::scoped`,
+     * which are implemented on the lock and the lock in Rc or Arc.
+     ************************************************************/
+    use crate::*;
+    use std::cell::RefCell;
+    use std::ops::ControlFlow;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, RwLock};
+
+    /// to go on behind `RefCell` while holding `borrow` or `borrow_mut`, used by `with_view_ref` and so on
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _borrow<Optic>(pub Optic);
+
+    /// to go on behind `Mutex` while holding `lock`, panics if the mutex is poisoned
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _lock<Optic>(pub Optic);
+
+    /// to go on behind `RwLock` while holding `read`, panics if the lock is poisoned
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _read<Optic>(pub Optic);
+
+    /// to go on behind `RwLock` while holding `write`, panics if the lock is poisoned
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _write<Optic>(pub Optic);
+
+    macro_rules! impl_split_at_lock {
+        ($($optic:ident),*) => {
+            $(
+                impl<Optic> SplitAtLock for $optic<Optic> {
+                    type Prefix = $optic<__>;
+                    type Rest = Locked<Optic>;
+
+                    #[inline]
+                    fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+                        ($optic(__), Locked(self.0))
+                    }
+                }
+
+                impl<Optic> SplitStaticPath for $optic<Optic> {
+                    type Prefix = __;
+                    type Rest = Self;
+
+                    #[inline]
+                    fn split_static_path(self) -> (__, Self) {
+                        (__, self)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_split_at_lock!(_borrow, _lock, _read, _write);
+
+    macro_rules! impl_lock_ref {
+        (<$param:ident> $ptr:ty => $lock:ty, |$cell:ident| $guard:expr) => {
+            impl<$param, Tr, Image: ?Sized> LockRef<Tr, Image, ByTraversal> for $ptr
+            where
+                $param: ScopedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn lock_ref(&self, optics: Tr, f: &mut dyn FnMut(&Image)) {
+                    let $cell: &$lock = self;
+                    (*$guard).with_each_ref(optics, f)
+                }
+            }
+
+            impl<$param, Tr, Image: ?Sized> LockRef<Tr, Image, ByLens> for $ptr
+            where
+                $param: ScopedLensRef<Tr, Image>,
+            {
+                #[inline]
+                fn lock_ref(&self, optics: Tr, f: &mut dyn FnMut(&Image)) {
+                    let $cell: &$lock = self;
+                    (*$guard).with_view_ref(optics, f)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_lock_mut {
+        (<$param:ident> $ptr:ty => $lock:ty, |$cell:ident| $guard:expr) => {
+            impl<$param, Tr, Image: ?Sized> LockMut<Tr, Image, ByTraversal> for $ptr
+            where
+                $param: ScopedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn lock_mut(&mut self, optics: Tr, f: &mut dyn FnMut(&mut Image)) {
+                    let $cell: &$lock = self;
+                    (*$guard).with_each_mut(optics, f)
+                }
+            }
+
+            impl<$param, Tr, Image: ?Sized> LockMut<Tr, Image, ByLens> for $ptr
+            where
+                $param: ScopedLensMut<Tr, Image>,
+            {
+                #[inline]
+                fn lock_mut(&mut self, optics: Tr, f: &mut dyn FnMut(&mut Image)) {
+                    let $cell: &$lock = self;
+                    (*$guard).with_view_mut(optics, f)
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_lock {
+        (<$param:ident> $lock:ty, |$cell:ident| ref: $guard_ref:expr, mut: $guard_mut:expr) => {
+            impl_lock_ref!(<$param> $lock => $lock, |$cell| $guard_ref);
+            impl_lock_ref!(<$param> Rc<$lock> => $lock, |$cell| $guard_ref);
+            impl_lock_ref!(<$param> Arc<$lock> => $lock, |$cell| $guard_ref);
+            impl_lock_mut!(<$param> $lock => $lock, |$cell| $guard_mut);
+            impl_lock_mut!(<$param> Rc<$lock> => $lock, |$cell| $guard_mut);
+            impl_lock_mut!(<$param> Arc<$lock> => $lock, |$cell| $guard_mut);
+        };
+    }
+
+    impl_lock!(<T> RefCell<T>, |cell| ref: cell.borrow(), mut: cell.borrow_mut());
+    impl_lock!(<T> Mutex<T>, |cell| ref: cell.lock().unwrap(), mut: cell.lock().unwrap());
+    impl_lock!(<T> RwLock<T>, |cell| ref: cell.read().unwrap(), mut: cell.write().unwrap());
+
+    // the lock optic focuses on the lock itself as a trait object,
+    // which goes on with the rest optics in `crate::scoped`
+    macro_rules! impl_lock_optic_ref {
+        (<$param:ident> $optic:ident, $ptr:ty, $focus:ident) => {
+            impl<$param: 'static, Tr, Image: ?Sized, By> TraversalRef<$optic<__>, dyn $focus<Tr, Image, By>> for $ptr
+            where
+                $ptr: $focus<Tr, Image, By>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, _optics: $optic<__>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a (dyn $focus<Tr, Image, By> + 'static)) -> ControlFlow<B>,
+                    dyn $focus<Tr, Image, By>: 'a,
+                {
+                    f(self)
+                }
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> IndexedTraversalRef<$optic<__>, dyn $focus<Tr, Image, By>> for $ptr
+            where
+                $ptr: $focus<Tr, Image, By>,
+            {
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> PrismRef<$optic<__>, dyn $focus<Tr, Image, By>> for $ptr
+            where
+                $ptr: $focus<Tr, Image, By>,
+            {
+                #[inline]
+                fn preview_ref(&self, _optics: $optic<__>) -> Option<&(dyn $focus<Tr, Image, By> + 'static)> {
+                    Some(self)
+                }
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> TryPrismRef<$optic<__>, dyn $focus<Tr, Image, By>> for $ptr
+            where
+                $ptr: $focus<Tr, Image, By>,
+            {
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> LensRef<$optic<__>, dyn $focus<Tr, Image, By>> for $ptr
+            where
+                $ptr: $focus<Tr, Image, By>,
+            {
+                #[inline]
+                fn view_ref(&self, _optics: $optic<__>) -> &(dyn $focus<Tr, Image, By> + 'static) {
+                    self
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_lock_optic_mut {
+        (<$param:ident> $optic:ident, $ptr:ty) => {
+            impl_lock_optic_ref!(<$param> $optic, $ptr, LockMut);
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> TraversalMut<$optic<__>, dyn LockMut<Tr, Image, By>> for $ptr
+            where
+                $ptr: LockMut<Tr, Image, By>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, _optics: $optic<__>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut (dyn LockMut<Tr, Image, By> + 'static)) -> ControlFlow<B>,
+                    dyn LockMut<Tr, Image, By>: 'a,
+                {
+                    f(self)
+                }
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> IndexedTraversalMut<$optic<__>, dyn LockMut<Tr, Image, By>> for $ptr
+            where
+                $ptr: LockMut<Tr, Image, By>,
+            {
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> PrismMut<$optic<__>, dyn LockMut<Tr, Image, By>> for $ptr
+            where
+                $ptr: LockMut<Tr, Image, By>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, _optics: $optic<__>) -> Option<&mut (dyn LockMut<Tr, Image, By> + 'static)> {
+                    Some(self)
+                }
+            }
+
+            impl<$param: 'static, Tr, Image: ?Sized, By> LensMut<$optic<__>, dyn LockMut<Tr, Image, By>> for $ptr
+            where
+                $ptr: LockMut<Tr, Image, By>,
+            {
+                #[inline]
+                fn view_mut(&mut self, _optics: $optic<__>) -> &mut (dyn LockMut<Tr, Image, By> + 'static) {
+                    self
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_lock_optic {
+        (ref: $optic:ident, <$param:ident> $lock:ty) => {
+            impl_lock_optic_ref!(<$param> $optic, $lock, LockRef);
+            impl_lock_optic_ref!(<$param> $optic, Rc<$lock>, LockRef);
+            impl_lock_optic_ref!(<$param> $optic, Arc<$lock>, LockRef);
+        };
+        (mut: $optic:ident, <$param:ident> $lock:ty) => {
+            impl_lock_optic!(ref: $optic, <$param> $lock);
+            impl_lock_optic_mut!(<$param> $optic, $lock);
+            impl_lock_optic_mut!(<$param> $optic, Rc<$lock>);
+            impl_lock_optic_mut!(<$param> $optic, Arc<$lock>);
+        };
+    }
+
+    impl_lock_optic!(mut: _borrow, <T> RefCell<T>);
+    impl_lock_optic!(mut: _lock, <T> Mutex<T>);
+    impl_lock_optic!(ref: _read, <T> RwLock<T>);
+    impl_lock_optic!(mut: _write, <T> RwLock<T>);
+}
+
+mod impl_codec {
+    /***********************************************************
+     * the codecs decoding the source into a temporary,
+     * which go on with the traits in `crate::scoped` like the locks,
+     * and encode the temporary back into the source after `with_each_mut`.
+     * the decoded value is moved out by the traits in `crate::owned`,
+     * since there is no reference to it but the temporary.
+     * the source is left untouched if it can't be decoded.
+     ************************************************************/
+    use crate::*;
+    #[cfg(feature = "base64")]
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use std::fmt::{self, Debug, Display, Formatter};
+    use std::marker::PhantomData;
+    use std::ops::ControlFlow;
+    use std::str::FromStr;
+
+    /// to go on with the value parsed from `String` by `FromStr`,
+    /// which is formatted back by `Display`, used by `with_each_mut`, `over_owned` and `review`,
+    /// and to move the value parsed from `&str` out by `preview_owned`
+    ///
+    /// ```ignore
+    /// let mut port = String::from("8080");
+    /// port.with_each_mut(optics!(_parsed::<u16>), |i| *i += 1);
+    /// assert_eq!(String::review(optics!(_parsed::<u16>), 8081u16), port);
+    /// assert_eq!("8081".preview_owned(optics!(_parsed::<u16>)), Some(8081));
+    /// ```
+    #[allow(non_camel_case_types)]
+    pub struct _parsed<T, Optic> {
+        pub optic: Optic,
+        pub target: PhantomData<fn() -> T>,
+    }
+
+    pub fn _parsed<T, Optic>(optic: Optic) -> _parsed<T, Optic> {
+        _parsed {
+            optic,
+            target: PhantomData,
+        }
+    }
+
+    // not derived, the target needn't be `Clone` or `Debug`
+    impl<T, Optic: Clone> Clone for _parsed<T, Optic> {
+        fn clone(&self) -> Self {
+            _parsed(self.optic.clone())
+        }
+    }
+
+    impl<T, Optic: Copy> Copy for _parsed<T, Optic> {}
+
+    impl<T, Optic: Debug> Debug for _parsed<T, Optic> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_struct("_parsed").field("optic", &self.optic).finish()
+        }
+    }
+
+    /// to go on with the `String` decoded from `Vec<u8>` as UTF-8
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _utf8<Optic>(pub Optic);
+
+    /// to go on with the bytes decoded from a hex `String`, which is encoded back in lowercase
+    #[cfg(feature = "hex")]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _hex<Optic>(pub Optic);
+
+    /// to go on with the bytes decoded from a `String` in the standard base64 with padding
+    #[cfg(feature = "base64")]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _base64<Optic>(pub Optic);
+
+    // the codec focuses on the source itself as a trait object like the locks
+    macro_rules! impl_codec_optic {
+        ($optic:ident<$($param:ident,)*>, $source:ty, $focus:ident) => {
+            impl<$($param,)* Tr, Image: ?Sized, By> TraversalRef<$optic<$($param,)* __>, dyn $focus<$optic<$($param,)* Tr>, Image, By>> for $source
+            where
+                $source: $focus<$optic<$($param,)* Tr>, Image, By>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, _optics: $optic<$($param,)* __>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a (dyn $focus<$optic<$($param,)* Tr>, Image, By> + 'static)) -> ControlFlow<B>,
+                    dyn $focus<$optic<$($param,)* Tr>, Image, By>: 'a,
+                {
+                    f(self)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized, By> IndexedTraversalRef<$optic<$($param,)* __>, dyn $focus<$optic<$($param,)* Tr>, Image, By>> for $source
+            where
+                $source: $focus<$optic<$($param,)* Tr>, Image, By>,
+            {
+            }
+        };
+    }
+
+    macro_rules! impl_codec {
+        (
+            $optic:ident<$($param:ident,)*>.$field:tt, $source:ty => $target:ty,
+            decode: |$src:ident| $decode:expr, [$($decode_bound:tt)*],
+            encode: |$tgt:ident| $encode:expr, [$($encode_bound:tt)*] $(,)?
+        ) => {
+            impl<$($param,)* Optic> SplitAtLock for $optic<$($param,)* Optic> {
+                type Prefix = $optic<$($param,)* __>;
+                type Rest = Locked<Self>;
+
+                #[inline]
+                fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+                    ($optic(__), Locked(self))
+                }
+            }
+
+            impl<$($param,)* Optic> SplitStaticPath for $optic<$($param,)* Optic> {
+                type Prefix = __;
+                type Rest = Self;
+
+                #[inline]
+                fn split_static_path(self) -> (__, Self) {
+                    (__, self)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized> LockRef<$optic<$($param,)* Tr>, Image, ByTraversal> for $source
+            where
+                $target: ScopedTraversalRef<Tr, Image>,
+                $($decode_bound)*
+            {
+                #[inline]
+                fn lock_ref(&self, optics: $optic<$($param,)* Tr>, f: &mut dyn FnMut(&Image)) {
+                    let $src = self;
+                    let decoded: Option<$target> = $decode;
+                    if let Some(target) = decoded {
+                        target.with_each_ref(optics.$field, f)
+                    }
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized> LockMut<$optic<$($param,)* Tr>, Image, ByTraversal> for $source
+            where
+                $target: ScopedTraversalMut<Tr, Image>,
+                $($decode_bound)*
+                $($encode_bound)*
+            {
+                #[inline]
+                fn lock_mut(&mut self, optics: $optic<$($param,)* Tr>, f: &mut dyn FnMut(&mut Image)) {
+                    let $src = &*self;
+                    let decoded: Option<$target> = $decode;
+                    if let Some(mut $tgt) = decoded {
+                        $tgt.with_each_mut(optics.$field, f);
+                        *self = $encode;
+                    }
+                }
+            }
+
+            impl_codec_optic!($optic<$($param,)*>, $source, LockRef);
+            impl_codec_optic!($optic<$($param,)*>, $source, LockMut);
+
+            impl<$($param,)* Tr, Image: ?Sized, By> TraversalMut<$optic<$($param,)* __>, dyn LockMut<$optic<$($param,)* Tr>, Image, By>> for $source
+            where
+                $source: LockMut<$optic<$($param,)* Tr>, Image, By>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, _optics: $optic<$($param,)* __>, mut f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut (dyn LockMut<$optic<$($param,)* Tr>, Image, By> + 'static)) -> ControlFlow<B>,
+                    dyn LockMut<$optic<$($param,)* Tr>, Image, By>: 'a,
+                {
+                    f(self)
+                }
+            }
+
+            impl<$($param,)* Tr, Image: ?Sized, By> IndexedTraversalMut<$optic<$($param,)* __>, dyn LockMut<$optic<$($param,)* Tr>, Image, By>> for $source
+            where
+                $source: LockMut<$optic<$($param,)* Tr>, Image, By>,
+            {
+            }
+
+            impl<$($param,)* Tr, Image> TraversalOwned<$optic<$($param,)* Tr>, Image> for $source
+            where
+                $target: TraversalOwned<Tr, Image>,
+                $($decode_bound)*
+                $($encode_bound)*
+            {
+                #[inline]
+                fn try_for_each_owned<B, F>(self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let $src = &self;
+                    let decoded: Option<$target> = $decode;
+                    match decoded {
+                        Some(target) => target.try_for_each_owned(optics.$field, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over_owned<F>(self, optics: $optic<$($param,)* Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let decoded: Option<$target> = {
+                        let $src = &self;
+                        $decode
+                    };
+                    match decoded {
+                        Some(target) => {
+                            let $tgt = target.over_owned(optics.$field, f);
+                            $encode
+                        }
+                        None => self,
+                    }
+                }
+            }
+
+            impl<$($param,)* Pm, Image> PrismOwned<$optic<$($param,)* Pm>, Image> for $source
+            where
+                $target: PrismOwned<Pm, Image>,
+                $($decode_bound)*
+            {
+                #[inline]
+                fn preview_owned(self, optics: $optic<$($param,)* Pm>) -> Option<Image> {
+                    let $src = &self;
+                    let decoded: Option<$target> = $decode;
+                    decoded?.preview_owned(optics.$field)
+                }
+            }
+
+            impl<$($param,)* Rv, Image> Review<$optic<$($param,)* Rv>, Image> for $source
+            where
+                $target: Review<Rv, Image>,
+                $($encode_bound)*
+            {
+                #[inline]
+                fn review(optics: $optic<$($param,)* Rv>, from: Image) -> Self {
+                    let $tgt = <$target>::review(optics.$field, from);
+                    $encode
+                }
+            }
+        };
+    }
+
+    impl_codec!(
+        _parsed<T,>.optic, String => T,
+        decode: |s| s.parse().ok(), [T: FromStr,],
+        encode: |x| x.to_string(), [T: Display,],
+    );
+
+    // a `str` can't be rebuilt, so it's only parsed
+    impl<T: FromStr, Pm, Image> PrismOwned<_parsed<T, Pm>, Image> for &str
+    where
+        T: PrismOwned<Pm, Image>,
+    {
+        #[inline]
+        fn preview_owned(self, optics: _parsed<T, Pm>) -> Option<Image> {
+            self.parse::<T>().ok()?.preview_owned(optics.optic)
+        }
+    }
+
+    impl_codec!(
+        _utf8<>.0, Vec<u8> => String,
+        decode: |bytes| std::str::from_utf8(bytes).ok().map(str::to_owned), [],
+        encode: |s| s.into_bytes(), [],
+    );
+
+    #[cfg(feature = "hex")]
+    impl_codec!(
+        _hex<>.0, String => Vec<u8>,
+        decode: |s| hex::decode(s).ok(), [],
+        encode: |bytes| hex::encode(bytes), [],
+    );
+
+    #[cfg(feature = "base64")]
+    impl_codec!(
+        _base64<>.0, String => Vec<u8>,
+        decode: |s| STANDARD.decode(s).ok(), [],
+        encode: |bytes| STANDARD.encode(bytes), [],
+    );
+}
+
+#[cfg(feature = "rayon")]
+mod impl_par {
+    /***********************************************************
+     * impl for _mapped in parallel
+     ************************************************************/
+    use crate::*;
+    use rayon::prelude::*;
+    use std::collections::VecDeque;
+
+    macro_rules! impl_par {
+        (<$item:ident $(, const $c:ident: $ct:ty)?> $collector:ty) => {
+            impl<Tr, Image: ?Sized, $item $(, const $c: $ct)?> ParTraversalMut<_mapped<Tr>, Image> for $collector
+            where
+                Tr: Clone + Sync,
+                $item: TraversalMut<Tr, Image> + Send,
+            {
+                #[inline]
+                fn par_for_each_mut<F>(&mut self, optics: _mapped<Tr>, f: F)
+                where
+                    F: Fn(&mut Image) + Send + Sync,
+                {
+                    self.par_iter_mut()
+                        .for_each(|t| t.for_each_mut(optics.0.clone(), &f))
+                }
+
+                #[inline]
+                fn par_traverse_mut(&mut self, optics: _mapped<Tr>) -> Vec<&mut Image>
+                where
+                    Image: Send,
+                {
+                    self.par_iter_mut()
+                        .flat_map_iter(|t| t.traverse_mut(optics.0.clone()))
+                        .collect()
+                }
+            }
+        };
+    }
+
+    impl_par!(<T> Vec<T>);
+    impl_par!(<T> VecDeque<T>);
+    impl_par!(<T> [T]);
+    impl_par!(<T, const N: usize> [T; N]);
+}
+
+#[cfg(feature = "serde_json")]
+mod impl_json {
+    /***********************************************************
+     * optics for serde_json::Value
+     ************************************************************/
+    use crate::*;
+    use crate::path::{OpticPath, PathMiss, Segment};
+    use super::impl_ix::no_focus;
+    use super::impl_predicate::{unit_mut, UNIT};
+    use serde_json::{Map, Number, Value};
+    use std::ops::ControlFlow;
+
+    /// to visit the field of a JSON object, implemented `Prism` and `Review`
+    ///
+    /// ```ignore
+    /// assert_eq!(json.preview_ref(optics!(_key("name")._String)), Some(&"lens".to_string()));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _key<K, Optic>(pub Optic, pub K);
+
+    /// to visit the element of a JSON array, implemented `Prism` and `Review`,
+    /// which fills the elements before with `null` when reviewing
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _idx<Optic>(pub Optic, pub usize);
+
+    /// to visit the elements of a JSON array or the fields of a JSON object, implemented `Traversal`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _values<Optic>(pub Optic);
+
+    /// to visit the JSON string, implemented `Prism` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _String<Optic>(pub Optic);
+
+    /// to visit the JSON number, implemented `Prism` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _Number<Optic>(pub Optic);
+
+    /// to visit the JSON boolean, implemented `Prism` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _Bool<Optic>(pub Optic);
+
+    /// to visit the JSON array, implemented `Prism` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _Array<Optic>(pub Optic);
+
+    /// to visit the JSON object, implemented `Prism` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _Object<Optic>(pub Optic);
+
+    /// to match the JSON `null`, implemented `Prism` and `Review` with the image `()`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _Null<Optic>(pub Optic);
+
+    // the access of the optics focusing on at most one part of a `Value`,
+    // implemented for the optics without the rest optics, i.e. `_key(key, ())`
+    trait JsonStep {
+        type Focus: 'static;
+
+        fn segment(&self) -> Segment;
+
+        fn get<'a>(&self, value: &'a Value) -> Option<&'a Self::Focus>;
+
+        fn get_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut Self::Focus>;
+
+        fn take(&self, value: Value) -> Option<Self::Focus>;
+
+        fn modify<F: FnOnce(Self::Focus) -> Self::Focus>(&self, value: Value, f: F) -> Value;
+
+        fn put(&self, focus: Self::Focus) -> Value;
+    }
+
+    impl<K: AsRef<str>> JsonStep for _key<K, ()> {
+        type Focus = Value;
+
+        fn segment(&self) -> Segment {
+            Segment::Key(format!("{:?}", self.1.as_ref()))
+        }
+
+        fn get<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+            value.get(self.1.as_ref())
+        }
+
+        fn get_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut Value> {
+            value.get_mut(self.1.as_ref())
+        }
+
+        fn take(&self, value: Value) -> Option<Value> {
+            match value {
+                Value::Object(mut map) => map.remove(self.1.as_ref()),
+                _ => None,
+            }
+        }
+
+        fn modify<F: FnOnce(Value) -> Value>(&self, mut value: Value, f: F) -> Value {
+            if let Some(x) = value.get_mut(self.1.as_ref()) {
+                *x = f(x.take());
+            }
+            value
+        }
+
+        fn put(&self, focus: Value) -> Value {
+            let mut map = Map::new();
+            map.insert(self.1.as_ref().to_string(), focus);
+            Value::Object(map)
+        }
+    }
+
+    impl JsonStep for _idx<()> {
+        type Focus = Value;
+
+        fn segment(&self) -> Segment {
+            Segment::Index(self.1)
+        }
+
+        fn get<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+            value.get(self.1)
+        }
+
+        fn get_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut Value> {
+            value.get_mut(self.1)
+        }
+
+        fn take(&self, value: Value) -> Option<Value> {
+            match value {
+                Value::Array(xs) => xs.into_iter().nth(self.1),
+                _ => None,
+            }
+        }
+
+        fn modify<F: FnOnce(Value) -> Value>(&self, mut value: Value, f: F) -> Value {
+            if let Some(x) = value.get_mut(self.1) {
+                *x = f(x.take());
+            }
+            value
+        }
+
+        fn put(&self, focus: Value) -> Value {
+            let mut xs = vec![Value::Null; self.1];
+            xs.push(focus);
+            Value::Array(xs)
+        }
+    }
+
+    macro_rules! impl_variant_step {
+        ($($optic:ident => $variant:ident($ty:ty)),* $(,)?) => {
+            $(
+                impl JsonStep for $optic<()> {
+                    type Focus = $ty;
+
+                    fn segment(&self) -> Segment {
+                        Segment::Variant(stringify!($variant))
+                    }
+
+                    fn get<'a>(&self, value: &'a Value) -> Option<&'a $ty> {
+                        match value {
+                            Value::$variant(x) => Some(x),
+                            _ => None,
+                        }
+                    }
+
+                    fn get_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut $ty> {
+                        match value {
+                            Value::$variant(x) => Some(x),
+                            _ => None,
+                        }
+                    }
+
+                    fn take(&self, value: Value) -> Option<$ty> {
+                        match value {
+                            Value::$variant(x) => Some(x),
+                            _ => None,
+                        }
+                    }
+
+                    fn modify<F: FnOnce($ty) -> $ty>(&self, value: Value, f: F) -> Value {
+                        match value {
+                            Value::$variant(x) => Value::$variant(f(x)),
+                            value => value,
+                        }
+                    }
+
+                    fn put(&self, focus: $ty) -> Value {
+                        Value::$variant(focus)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_variant_step! {
+        _String => String(String),
+        _Number => Number(Number),
+        _Bool => Bool(bool),
+        _Array => Array(Vec<Value>),
+        _Object => Object(Map<String, Value>),
+    }
+
+    impl JsonStep for _Null<()> {
+        type Focus = ();
+
+        fn segment(&self) -> Segment {
+            Segment::Variant("Null")
+        }
+
+        fn get<'a>(&self, value: &'a Value) -> Option<&'a ()> {
+            value.as_null().map(|()| UNIT)
+        }
+
+        fn get_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut ()> {
+            value.as_null().map(|()| unit_mut())
+        }
+
+        fn take(&self, value: Value) -> Option<()> {
+            value.as_null()
+        }
+
+        fn modify<F: FnOnce(())>(&self, value: Value, f: F) -> Value {
+            if value.is_null() {
+                f(());
+            }
+            value
+        }
+
+        fn put(&self, (): ()) -> Value {
+            Value::Null
+        }
+    }
+
+    macro_rules! impl_json_prism {
+        ($optic:ident<$($param:ident),*>($($field:ident),*)) => {
+            impl<Image: ?Sized, Tr, $($param),*> TraversalRef<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: TraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_ref<'a, B, F>(&'a self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    match $optic((), $($field),*).get(self) {
+                        Some(x) => x.try_for_each_ref(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> IndexedTraversalRef<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: IndexedTraversalRef<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_ref<'a, B, F>(
+                    &'a self,
+                    optics: $optic<$($param,)* Tr>,
+                    path: &mut OpticPath,
+                    f: F,
+                ) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    let step = $optic((), $($field),*);
+                    path.push(step.segment());
+                    let flow = match step.get(self) {
+                        Some(x) => x.try_ifor_each_ref(optics, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> TraversalMut<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: TraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_mut<'a, B, F>(&'a mut self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    match $optic((), $($field),*).get_mut(self) {
+                        Some(x) => x.try_for_each_mut(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> IndexedTraversalMut<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: IndexedTraversalMut<Tr, Image>,
+            {
+                #[inline]
+                fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: $optic<$($param,)* Tr>, path: &mut OpticPath, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+                    Image: 'a,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    let step = $optic((), $($field),*);
+                    path.push(step.segment());
+                    let flow = match step.get_mut(self) {
+                        Some(x) => x.try_ifor_each_mut(optics, path, f),
+                        None => ControlFlow::Continue(()),
+                    };
+                    path.pop();
+                    flow
+                }
+            }
+
+            impl<Image, Tr, $($param),*> Traversal<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: Traversal<Tr, Image>,
+            {
+                #[inline]
+                fn try_for_each_move<B, F>(self, optics: $optic<$($param,)* Tr>, f: F) -> ControlFlow<B>
+                where
+                    F: FnMut(Image) -> ControlFlow<B>,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    match $optic((), $($field),*).take(self) {
+                        Some(x) => x.try_for_each_move(optics, f),
+                        None => ControlFlow::Continue(()),
+                    }
+                }
+
+                #[inline]
+                fn over<F>(self, optics: $optic<$($param,)* Tr>, f: F) -> Self
+                where
+                    F: FnMut(Image) -> Image,
+                {
+                    let $optic(optics, $($field),*) = optics;
+                    $optic((), $($field),*).modify(self, |x| x.over(optics, f))
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> PrismRef<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: PrismRef<Tr, Image>,
+            {
+                #[inline]
+                fn preview_ref(&self, optics: $optic<$($param,)* Tr>) -> Option<&Image> {
+                    let $optic(optics, $($field),*) = optics;
+                    $optic((), $($field),*).get(self)?.preview_ref(optics)
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> TryPrismRef<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: TryPrismRef<Tr, Image>,
+            {
+                #[inline]
+                fn miss_ref(&self, optics: $optic<$($param,)* Tr>, path: &mut OpticPath) -> Option<PathMiss> {
+                    let $optic(optics, $($field),*) = optics;
+                    let step = $optic((), $($field),*);
+                    match step.get(self) {
+                        Some(x) => {
+                            path.push(step.segment());
+                            let miss = x.miss_ref(optics, path);
+                            path.pop();
+                            miss
+                        }
+                        None => no_focus(path, stringify!($optic)),
+                    }
+                }
+            }
+
+            impl<Image: ?Sized, Tr, $($param),*> PrismMut<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: PrismMut<Tr, Image>,
+            {
+                #[inline]
+                fn preview_mut(&mut self, optics: $optic<$($param,)* Tr>) -> Option<&mut Image> {
+                    let $optic(optics, $($field),*) = optics;
+                    $optic((), $($field),*).get_mut(self)?.preview_mut(optics)
+                }
+            }
+
+            impl<Image, Tr, $($param),*> Prism<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: Prism<Tr, Image>,
+            {
+                #[inline]
+                fn preview(self, optics: $optic<$($param,)* Tr>) -> Option<Image> {
+                    let $optic(optics, $($field),*) = optics;
+                    $optic((), $($field),*).take(self)?.preview(optics)
+                }
+            }
+
+            impl<Image, Tr, $($param),*> Review<$optic<$($param,)* Tr>, Image> for Value
+            where
+                $optic<$($param,)* ()>: JsonStep,
+                <$optic<$($param,)* ()> as JsonStep>::Focus: Review<Tr, Image>,
+            {
+                #[inline]
+                fn review(optics: $optic<$($param,)* Tr>, from: Image) -> Self {
+                    let $optic(optics, $($field),*) = optics;
+                    let focus = Review::review(optics, from);
+                    JsonStep::put(&$optic((), $($field),*), focus)
+                }
+            }
+        };
+    }
+
+    impl_json_prism!(_key<K>(key));
+    impl_json_prism!(_idx<>(index));
+    impl_json_prism!(_String<>());
+    impl_json_prism!(_Number<>());
+    impl_json_prism!(_Bool<>());
+    impl_json_prism!(_Array<>());
+    impl_json_prism!(_Object<>());
+    impl_json_prism!(_Null<>());
+
+    impl<Tr: Clone, Image: ?Sized> TraversalRef<_values<Tr>, Image> for Value
+    where
+        Value: TraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: _values<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match self {
+                Value::Array(xs) => xs
+                    .iter()
+                    .try_for_each(|x| x.try_for_each_ref(optics.0.clone(), &mut f)),
+                Value::Object(map) => map
+                    .values()
+                    .try_for_each(|x| x.try_for_each_ref(optics.0.clone(), &mut f)),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<Tr: Clone, Image: ?Sized> IndexedTraversalRef<_values<Tr>, Image> for Value
+    where
+        Value: IndexedTraversalRef<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: _values<Tr>,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match self {
+                Value::Array(xs) => xs.iter().enumerate().try_for_each(|(i, x)| {
+                    path.push(Segment::Index(i));
+                    let flow = x.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                    path.pop();
+                    flow
+                }),
+                Value::Object(map) => map.iter().try_for_each(|(k, x)| {
+                    path.push(Segment::Key(format!("{:?}", k)));
+                    let flow = x.try_ifor_each_ref(optics.0.clone(), path, &mut f);
+                    path.pop();
+                    flow
+                }),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<Tr: Clone, Image: ?Sized> TraversalMut<_values<Tr>, Image> for Value
+    where
+        Value: TraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: _values<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match self {
+                Value::Array(xs) => xs
+                    .iter_mut()
+                    .try_for_each(|x| x.try_for_each_mut(optics.0.clone(), &mut f)),
+                Value::Object(map) => map
+                    .values_mut()
+                    .try_for_each(|x| x.try_for_each_mut(optics.0.clone(), &mut f)),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<Tr: Clone, Image: ?Sized> IndexedTraversalMut<_values<Tr>, Image> for Value
+    where
+        Value: IndexedTraversalMut<Tr, Image>,
+    {
+        #[inline]
+        fn try_ifor_each_mut<'a, B, F>(&'a mut self, optics: _values<Tr>, path: &mut OpticPath, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            match self {
+                Value::Array(xs) => xs.iter_mut().enumerate().try_for_each(|(i, x)| {
+                    path.push(Segment::Index(i));
+                    let flow = x.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                    path.pop();
+                    flow
+                }),
+                Value::Object(map) => map.iter_mut().try_for_each(|(k, x)| {
+                    path.push(Segment::Key(format!("{:?}", k)));
+                    let flow = x.try_ifor_each_mut(optics.0.clone(), path, &mut f);
+                    path.pop();
+                    flow
+                }),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    impl<Tr: Clone, Image> Traversal<_values<Tr>, Image> for Value
+    where
+        Value: Traversal<Tr, Image>,
+    {
+        #[inline]
+        fn try_for_each_move<B, F>(self, optics: _values<Tr>, mut f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+        {
+            match self {
+                Value::Array(xs) => xs
+                    .into_iter()
+                    .try_for_each(|x| x.try_for_each_move(optics.0.clone(), &mut f)),
+                Value::Object(map) => map
+                    .into_iter()
+                    .try_for_each(|(_, x)| x.try_for_each_move(optics.0.clone(), &mut f)),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+
+        #[inline]
+        fn over<F>(self, optics: _values<Tr>, mut f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+        {
+            match self {
+                Value::Array(xs) => Value::Array(
+                    xs.into_iter()
+                        .map(|x| x.over(optics.0.clone(), &mut f))
+                        .collect(),
+                ),
+                Value::Object(map) => Value::Object(
+                    map.into_iter()
+                        .map(|(k, x)| (k, x.over(optics.0.clone(), &mut f)))
+                        .collect(),
+                ),
+                value => value,
+            }
         }
     }
 }
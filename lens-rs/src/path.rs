@@ -0,0 +1,459 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::{self, Debug, Display};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A value whose substructures can be looked up by name at runtime.
+///
+/// Derived by `#[derive(Lens)]` and `#[derive(Prism)]` for the types marked with `#[optic(path)]`,
+/// registering the names of the fields and variants marked with `#[optic]`.
+///
+/// ```ignore
+/// #[derive(Debug, Lens)]
+/// #[optic(path)]
+/// struct Server {
+///     #[optic]
+///     port: u16,
+///     #[optic]
+///     tls: Option<Tls>,
+/// }
+/// ```
+pub trait Reflect: Any + Debug {
+    /// the field, the tuple field like `_1`, or the payload of the variant named `name`
+    fn field_ref(&self, _name: &str) -> Option<&dyn Reflect> {
+        None
+    }
+
+    /// the element at `index`
+    fn index_ref(&self, _index: usize) -> Option<&dyn Reflect> {
+        None
+    }
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_debug(&self) -> &dyn Debug;
+}
+
+/// the reason a path can't be resolved
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathError {
+    /// the path is malformed from `rest` on
+    Syntax { rest: String },
+    /// `segment`, starting at byte `offset` of the path, doesn't exist in the value
+    Unresolved { segment: String, offset: usize },
+    /// the path is resolved, but not to a value of type `expected`
+    Mismatch { expected: &'static str },
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Syntax { rest } => write!(f, "malformed path at `{}`", rest),
+            PathError::Unresolved { segment, offset } => {
+                write!(
+                    f,
+                    "can't resolve `{}` at byte {} of the path",
+                    segment, offset
+                )
+            }
+            PathError::Mismatch { expected } => {
+                write!(f, "the path doesn't lead to a `{}`", expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// a step of the path leading to a focus
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Segment {
+    /// a field, or a tuple field like `_1`
+    Field(&'static str),
+    /// a variant like `Some`
+    Variant(&'static str),
+    /// an element taken by `_mapped` or `_ix`
+    Index(usize),
+    /// a key taken by `_ix`, written by `PathKey`
+    Key(String),
+}
+
+/// how a map key is written in an `OpticPath`
+///
+/// Only the indexed traversals and the try previews write the keys,
+/// the other optics take the maps keyed by any type.
+/// Every `Debug` key is written in its `Debug` format.
+/// A key type without `Debug` opts in with an empty impl, and is written as `_`:
+///
+/// ```ignore
+/// struct Token(u64);
+/// impl lens_rs::path::PathKey for Token {}
+/// ```
+pub trait PathKey {
+    fn path_key(&self) -> String {
+        String::from("_")
+    }
+}
+
+impl<T: Debug + ?Sized> PathKey for T {
+    fn path_key(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// the path leading to a focus, printed like `_1.Ok._0[2]`
+///
+/// Built by `itraverse_ref` and `itraverse_mut`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct OpticPath(Vec<Segment>);
+
+impl OpticPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+
+    pub fn push(&mut self, segment: Segment) {
+        self.0.push(segment)
+    }
+
+    pub fn pop(&mut self) -> Option<Segment> {
+        self.0.pop()
+    }
+}
+
+impl Display for OpticPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Field(name) | Segment::Variant(name) if i == 0 => write!(f, "{}", name)?,
+                Segment::Field(name) | Segment::Variant(name) => write!(f, ".{}", name)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+                Segment::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// the reason an optic previews nothing, returned by `try_preview_ref`, `try_preview_mut`
+/// and `try_preview`
+///
+/// ```ignore
+/// let x = (1, Err::<(Vec<i32>, ()), _>(0));
+/// let miss = x.try_preview_ref(optics!(_1.Ok._0.[2])).unwrap_err();
+/// assert_eq!(miss.depth(), 1);
+/// assert_eq!(miss.to_string(), "expected `Ok`, found other variant at `_1`");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PathMiss {
+    /// the path to the value the failing optic is applied to
+    pub at: OpticPath,
+    pub reason: Miss,
+}
+
+/// the way an optic misses
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Miss {
+    /// the value is a variant other than `expected`
+    Variant { expected: &'static str },
+    /// `index`, in its `Debug` format, is out of the bounds of a sequence of length `len`
+    Index { index: String, len: usize },
+    /// there is no entry for `key`, written by `PathKey`
+    Key { key: String },
+    /// the optic named `optic` has no focus in the value
+    NoFocus { optic: &'static str },
+}
+
+impl PathMiss {
+    pub fn new(at: &OpticPath, reason: Miss) -> Self {
+        Self {
+            at: at.clone(),
+            reason,
+        }
+    }
+
+    /// the number of segments leading to the value the failing optic is applied to
+    pub fn depth(&self) -> usize {
+        self.at.segments().len()
+    }
+}
+
+impl Display for PathMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            Miss::Variant { expected } => {
+                write!(f, "expected `{}`, found other variant", expected)?
+            }
+            Miss::Index { index, .. } => write!(f, "index {} out of bounds", index)?,
+            Miss::Key { key } => write!(f, "no entry for key {}", key)?,
+            Miss::NoFocus { optic } => write!(f, "`{}` has no focus", optic)?,
+        }
+        match self.depth() {
+            0 => Ok(()),
+            _ => write!(f, " at `{}`", self.at),
+        }
+    }
+}
+
+impl std::error::Error for PathMiss {}
+
+/// resolve a path like `"a._1.Some[2]"` or `"map[\"key\"]"`, returns the value it leads to
+///
+/// ```ignore
+/// use lens_rs::path;
+/// let x = (1, Some(vec![2, 3, 4]));
+/// assert_eq!(path::get_ref(&x, "_1.Some[2]")?.downcast_ref::<i32>(), Some(&4));
+/// ```
+pub fn get_ref<'a>(value: &'a dyn Reflect, path: &str) -> Result<&'a dyn Any, PathError> {
+    resolve(value, path).map(Reflect::as_any)
+}
+
+/// resolve a path like `"a._1.Some[2]"`, returns the value it leads to for printing
+pub fn get_debug<'a>(value: &'a dyn Reflect, path: &str) -> Option<&'a dyn Debug> {
+    resolve(value, path).ok().map(Reflect::as_debug)
+}
+
+/// resolve a path like `"a._1.Some[2]"`, returns the value it leads to if it's a `T`
+pub fn get_as<'a, T: Any>(value: &'a dyn Reflect, path: &str) -> Result<&'a T, PathError> {
+    resolve(value, path)?
+        .as_any()
+        .downcast_ref()
+        .ok_or(PathError::Mismatch {
+            expected: std::any::type_name::<T>(),
+        })
+}
+
+fn resolve<'a>(value: &'a dyn Reflect, path: &str) -> Result<&'a dyn Reflect, PathError> {
+    let mut focus = value;
+    let mut rest = path;
+    while !rest.is_empty() {
+        let offset = path.len() - rest.len();
+        let (segment, tail) = split_segment(rest)?;
+        let next = match segment.strip_prefix('[') {
+            Some(index) => {
+                let index = index.trim_end_matches(']').trim();
+                match index
+                    .strip_prefix('"')
+                    .and_then(|key| key.strip_suffix('"'))
+                {
+                    Some(key) => focus.field_ref(key),
+                    None => index.parse().ok().and_then(|i| focus.index_ref(i)),
+                }
+            }
+            None => focus.field_ref(segment),
+        };
+        focus = next.ok_or_else(|| PathError::Unresolved {
+            segment: segment.to_string(),
+            offset,
+        })?;
+        rest = tail;
+    }
+    Ok(focus)
+}
+
+// split `rest` into the first segment and the rest after it
+fn split_segment(rest: &str) -> Result<(&str, &str), PathError> {
+    let syntax_error = || PathError::Syntax {
+        rest: rest.to_string(),
+    };
+    let end = if rest.starts_with('[') {
+        rest.find(']').ok_or_else(syntax_error)? + 1
+    } else {
+        rest.find(['.', '[']).unwrap_or(rest.len())
+    };
+    if end == 0 {
+        return Err(syntax_error());
+    }
+
+    let (segment, tail) = rest.split_at(end);
+    match tail.strip_prefix('.') {
+        Some("") => Err(syntax_error()),
+        Some(tail) if !tail.starts_with('[') => Ok((segment, tail)),
+        Some(_) => Err(syntax_error()),
+        None if tail.is_empty() || tail.starts_with('[') => Ok((segment, tail)),
+        None => Err(syntax_error()),
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Reflect for $ty {
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_debug(&self) -> &dyn Debug {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf!(
+    (),
+    bool,
+    char,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    String,
+    &'static str,
+);
+
+macro_rules! impl_tuple {
+    ($(($param:ident, $index:tt, $name:literal))*) => {
+        impl<$($param: Reflect),*> Reflect for ($($param,)*) {
+            fn field_ref(&self, name: &str) -> Option<&dyn Reflect> {
+                match name {
+                    $($name => Some(&self.$index),)*
+                    _ => None,
+                }
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_debug(&self) -> &dyn Debug {
+                self
+            }
+        }
+    };
+}
+
+// std implements `Debug` for the tuples up to 12 fields
+macro_rules! impl_tuples {
+    ([$($done:tt)*]) => {};
+    ([$($done:tt)*] $next:tt $($rest:tt)*) => {
+        impl_tuple!($($done)* $next);
+        impl_tuples!([$($done)* $next] $($rest)*);
+    };
+}
+
+impl_tuples!([](A, 0, "_0")(B, 1, "_1")(C, 2, "_2")(D, 3, "_3")(
+    E, 4, "_4"
+)(F, 5, "_5")(G, 6, "_6")(H, 7, "_7")(I, 8, "_8")(J, 9, "_9")(
+    K, 10, "_10"
+)(L, 11, "_11"));
+
+impl<T: Reflect> Reflect for Option<T> {
+    fn field_ref(&self, name: &str) -> Option<&dyn Reflect> {
+        match (name, self) {
+            ("Some", Some(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+impl<T: Reflect, E: Reflect> Reflect for Result<T, E> {
+    fn field_ref(&self, name: &str) -> Option<&dyn Reflect> {
+        match (name, self) {
+            ("Ok", Ok(x)) => Some(x),
+            ("Err", Err(e)) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_debug(&self) -> &dyn Debug {
+        self
+    }
+}
+
+macro_rules! impl_seq {
+    (<$param:ident, $(const $c:ident: $ct:ty)?> $t:ty) => {
+        impl<$param: Reflect, $(const $c: $ct)?> Reflect for $t {
+            fn index_ref(&self, index: usize) -> Option<&dyn Reflect> {
+                self.get(index).map(|x| x as &dyn Reflect)
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_debug(&self) -> &dyn Debug {
+                self
+            }
+        }
+    };
+}
+
+impl_seq!(<T,> Vec<T>);
+impl_seq!(<T,> VecDeque<T>);
+impl_seq!(<T, const N: usize> [T; N]);
+
+macro_rules! impl_map {
+    ($map:ident) => {
+        impl<V: Reflect> Reflect for $map<String, V> {
+            fn field_ref(&self, name: &str) -> Option<&dyn Reflect> {
+                self.get(name).map(|x| x as &dyn Reflect)
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_debug(&self) -> &dyn Debug {
+                self
+            }
+        }
+    };
+}
+
+impl_map!(HashMap);
+impl_map!(BTreeMap);
+
+macro_rules! impl_ptr {
+    ($($ptr:ident),*) => {
+        $(
+            impl<T: Reflect> Reflect for $ptr<T> {
+                fn field_ref(&self, name: &str) -> Option<&dyn Reflect> {
+                    (**self).field_ref(name)
+                }
+
+                fn index_ref(&self, index: usize) -> Option<&dyn Reflect> {
+                    (**self).index_ref(index)
+                }
+
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_debug(&self) -> &dyn Debug {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_ptr!(Box, Rc, Arc);
@@ -15,6 +15,56 @@ pub mod review {
     }
 }
 
+/// # Iso
+///
+/// A trait representing the optics describes a lossless conversion.
+/// An `Iso` can convert a value to its image by `.to()`, and back by `Iso::from_image`.
+///
+/// The `_inner` iso of a newtype is a `Lens` as well.
+/// `_tuple` and `_re` build their image on the fly, so they only convert by value.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// #[derive(Iso)]
+/// struct Meters(f64);
+///
+/// assert_eq!(Meters(1.0).to(optics!(_inner)), 1.0);
+/// assert_eq!(Meters(1.0).view_ref(optics!(_inner)), &1.0);
+/// let meters: Meters = Iso::from_image(optics!(_inner), 1.0);
+/// let meters: Meters = 1.0.to(optics!(_re(optics!(_inner))));
+/// assert_eq!(meters, Meters(1.0));
+/// ```
+pub mod iso {
+    use crate::*;
+
+    pub trait Iso<Optics, Image>: Review<Optics, Image> {
+        fn to(self, optics: Optics) -> Image;
+
+        /// convert the image back, the inverse of `to`,
+        /// not named `from` to stay apart from `From::from`
+        fn from_image(optics: Optics, from: Image) -> Self
+        where
+            Self: Sized,
+        {
+            Review::review(optics, from)
+        }
+    }
+
+    /// a struct with a single field, derived by `#[derive(Iso)]` and focused by `_inner`
+    pub trait Newtype {
+        type Inner;
+
+        fn inner_ref(&self) -> &Self::Inner;
+
+        fn inner_mut(&mut self) -> &mut Self::Inner;
+
+        fn into_inner(self) -> Self::Inner;
+
+        fn from_inner(inner: Self::Inner) -> Self;
+    }
+}
+
 /// # Traversal
 ///
 /// A trait representing the optics allows you to traverse over a structure and change out its contents.
@@ -27,24 +77,197 @@ pub mod review {
 /// x.traverse_mut(optics!(_1._mapped._Some._0))
 ///     .into_iter()
 ///     .for_each(|i| *i += 1);
+///
+/// let mut sum = 0;
+/// x.for_each_ref(optics!(_1._mapped.Some._both), |i| sum += i);
+/// assert_eq!(sum, 6);
 /// assert_eq!(x.traverse(optics!(_1._mapped.Some._0)), vec![3]);
+///
+/// let paths = x.itraverse_ref(optics!(_1._mapped.Some._0));
+/// assert_eq!(paths[0].0.to_string(), "_1[0].Some._0");
+///
+/// let x = (1, vec![Some((2, 3)), None]).over(optics!(_1._mapped.Some._0), |i| i * 10);
+/// assert_eq!(x.set(optics!(_0), 0), (0, vec![Some((20, 3)), None]));
 /// ```
 pub mod traversal {
+    use crate::path::OpticPath;
+    use std::ops::ControlFlow;
+
     /// the immutable version of Traversal
     pub trait TraversalRef<Opt, Image: ?Sized> {
-        fn traverse_ref(&self, optics: Opt) -> Vec<&Image>;
+        /// visit every focus in order, stopping as soon as `f` breaks
+        fn try_for_each_ref<'a, B, F>(&'a self, optics: Opt, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a Image) -> ControlFlow<B>,
+            Image: 'a;
+
+        /// visit every focus in order, without collecting them
+        fn for_each_ref<'a, F>(&'a self, optics: Opt, mut f: F)
+        where
+            F: FnMut(&'a Image),
+            Image: 'a,
+        {
+            let _ = self.try_for_each_ref(optics, |x| -> ControlFlow<()> {
+                f(x);
+                ControlFlow::Continue(())
+            });
+        }
+
+        fn traverse_ref(&self, optics: Opt) -> Vec<&Image> {
+            let mut vec = vec![];
+            self.for_each_ref(optics, |x| vec.push(x));
+            vec
+        }
+    }
+
+    /// the indexed version of TraversalRef, telling the path leading to every focus
+    ///
+    /// Apart from the optics of this crate, the default adds no segment to the path,
+    /// so a custom optic opts in with an empty impl.
+    pub trait IndexedTraversalRef<Opt, Image: ?Sized>: TraversalRef<Opt, Image> {
+        /// visit every focus in order with the path leading to it appended to `path`,
+        /// stopping as soon as `f` breaks
+        fn try_ifor_each_ref<'a, B, F>(
+            &'a self,
+            optics: Opt,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let path = &*path;
+            self.try_for_each_ref(optics, |x| f(path, x))
+        }
+
+        /// collect every focus with the path leading to it
+        fn itraverse_ref(&self, optics: Opt) -> Vec<(OpticPath, &Image)> {
+            let mut vec = vec![];
+            let _ = self.try_ifor_each_ref(
+                optics,
+                &mut OpticPath::new(),
+                |path, x| -> ControlFlow<()> {
+                    vec.push((path.clone(), x));
+                    ControlFlow::Continue(())
+                },
+            );
+            vec
+        }
     }
 
     /// the mutable version of Traversal
     pub trait TraversalMut<Optics, Image: ?Sized>: TraversalRef<Optics, Image> {
-        fn traverse_mut(&mut self, optics: Optics) -> Vec<&mut Image>;
+        /// visit every focus in order, stopping as soon as `f` breaks
+        fn try_for_each_mut<'a, B, F>(&'a mut self, optics: Optics, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(&'a mut Image) -> ControlFlow<B>,
+            Image: 'a;
+
+        /// visit every focus in order, without collecting them
+        fn for_each_mut<'a, F>(&'a mut self, optics: Optics, mut f: F)
+        where
+            F: FnMut(&'a mut Image),
+            Image: 'a,
+        {
+            let _ = self.try_for_each_mut(optics, |x| -> ControlFlow<()> {
+                f(x);
+                ControlFlow::Continue(())
+            });
+        }
+
+        fn traverse_mut(&mut self, optics: Optics) -> Vec<&mut Image> {
+            let mut vec = vec![];
+            self.for_each_mut(optics, |x| vec.push(x));
+            vec
+        }
+    }
+
+    /// the indexed version of TraversalMut, telling the path leading to every focus
+    pub trait IndexedTraversalMut<Optics, Image: ?Sized>:
+        TraversalMut<Optics, Image> + IndexedTraversalRef<Optics, Image>
+    {
+        /// visit every focus in order with the path leading to it appended to `path`,
+        /// stopping as soon as `f` breaks
+        fn try_ifor_each_mut<'a, B, F>(
+            &'a mut self,
+            optics: Optics,
+            path: &mut OpticPath,
+            mut f: F,
+        ) -> ControlFlow<B>
+        where
+            F: FnMut(&OpticPath, &'a mut Image) -> ControlFlow<B>,
+            Image: 'a,
+        {
+            let path = &*path;
+            self.try_for_each_mut(optics, |x| f(path, x))
+        }
+
+        /// visit every focus in order with the path leading to it appended to `path`
+        fn ifor_each_mut<'a, F>(&'a mut self, optics: Optics, path: &mut OpticPath, mut f: F)
+        where
+            F: FnMut(&OpticPath, &'a mut Image),
+            Image: 'a,
+        {
+            let _ = self.try_ifor_each_mut(optics, path, |path, x| -> ControlFlow<()> {
+                f(path, x);
+                ControlFlow::Continue(())
+            });
+        }
+
+        /// collect every focus with the path leading to it
+        fn itraverse_mut(&mut self, optics: Optics) -> Vec<(OpticPath, &mut Image)> {
+            let mut vec = vec![];
+            self.ifor_each_mut(optics, &mut OpticPath::new(), |path, x| {
+                vec.push((path.clone(), x))
+            });
+            vec
+        }
     }
 
     /// the movable version of Traversal
     pub trait Traversal<Optics, Image>: TraversalMut<Optics, Image> {
+        /// move every focus out in order, dropping the rest as soon as `f` breaks
+        fn try_for_each_move<B, F>(self, optics: Optics, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+            Self: Sized;
+
+        /// move every focus out in order, without collecting them
+        fn for_each_move<F>(self, optics: Optics, mut f: F)
+        where
+            F: FnMut(Image),
+            Self: Sized,
+        {
+            let _ = self.try_for_each_move(optics, |x| -> ControlFlow<()> {
+                f(x);
+                ControlFlow::Continue(())
+            });
+        }
+
         fn traverse(self, optics: Optics) -> Vec<Image>
         where
+            Self: Sized,
+        {
+            let mut vec = vec![];
+            self.for_each_move(optics, |x| vec.push(x));
+            vec
+        }
+
+        /// rebuild the structure with `f` applied to every focus
+        fn over<F>(self, optics: Optics, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
             Self: Sized;
+
+        /// rebuild the structure with every focus replaced by `value`
+        fn set(self, optics: Optics, value: Image) -> Self
+        where
+            Image: Clone,
+            Self: Sized,
+        {
+            self.over(optics, move |_| value.clone())
+        }
     }
 }
 
@@ -61,12 +284,52 @@ pub mod traversal {
 /// ```
 ///
 pub mod prism {
+    use crate::path::{Miss, OpticPath, PathMiss};
     use crate::*;
+
+    // the optic named by the default `miss_ref`, which can't tell which optic it is
+    const PRISM: &str = "prism";
+
+    // the miss `try_preview_xx` reports when `miss_ref` finds nothing wrong
+    fn no_focus() -> PathMiss {
+        PathMiss::new(&OpticPath::new(), Miss::NoFocus { optic: PRISM })
+    }
+
     /// the immutable version of Prism
     pub trait PrismRef<Optics, Image: ?Sized>: TraversalRef<Optics, Image> {
         fn preview_ref(&self, optics: Optics) -> Option<&Image>;
     }
 
+    /// the version of PrismRef telling why it previews nothing
+    ///
+    /// A custom optic opts in with an empty impl, reported as a `prism` with no focus.
+    pub trait TryPrismRef<Optics, Image: ?Sized>: PrismRef<Optics, Image> {
+        /// why `preview_ref` has nothing, where `path` leads to `self`,
+        /// or `None` if it has a focus.
+        /// The default reports a `prism` with no focus at `path`,
+        /// the optics of this crate override it to name themselves and look into the rest optics
+        fn miss_ref(&self, optics: Optics, path: &mut OpticPath) -> Option<PathMiss> {
+            match self.preview_ref(optics) {
+                Some(_) => None,
+                None => Some(PathMiss::new(path, Miss::NoFocus { optic: PRISM })),
+            }
+        }
+
+        /// previews the focus, or tells which optic misses at which depth
+        fn try_preview_ref(&self, optics: Optics) -> Result<&Image, PathMiss>
+        where
+            Optics: Clone,
+        {
+            match self.preview_ref(optics.clone()) {
+                Some(x) => Ok(x),
+                None => {
+                    let miss = self.miss_ref(optics, &mut OpticPath::new());
+                    Err(miss.unwrap_or_else(no_focus))
+                }
+            }
+        }
+    }
+
     /// the mutable version of Prism
     pub trait PrismMut<Optics, Image: ?Sized>:
         PrismRef<Optics, Image> + TraversalMut<Optics, Image>
@@ -74,12 +337,79 @@ pub mod prism {
         fn preview_mut(&mut self, optics: Optics) -> Option<&mut Image>;
     }
 
+    /// the version of PrismMut telling why it previews nothing,
+    /// implemented for every `PrismMut` and `TryPrismRef`
+    pub trait TryPrismMut<Optics, Image: ?Sized>:
+        PrismMut<Optics, Image> + TryPrismRef<Optics, Image>
+    {
+        /// previews the focus mutably, or tells which optic misses at which depth.
+        /// The miss is explained by reading only after `preview_mut` has nothing,
+        /// so `_entry`, `_non` and the like insert and hit as `preview_mut` does
+        fn try_preview_mut(&mut self, optics: Optics) -> Result<&mut Image, PathMiss>
+        where
+            Optics: Clone,
+        {
+            let this: *mut Self = self;
+            // SAFETY: `this` is borrowed again only if `preview_mut` has nothing,
+            // when no reference from the first borrow is given out
+            unsafe {
+                match (*this).preview_mut(optics.clone()) {
+                    Some(x) => Ok(x),
+                    None => {
+                        let miss = TryPrismRef::<Optics, Image>::miss_ref(
+                            &*this,
+                            optics,
+                            &mut OpticPath::new(),
+                        );
+                        Err(miss.unwrap_or_else(no_focus))
+                    }
+                }
+            }
+        }
+    }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> TryPrismMut<Optics, Image> for T where
+        T: PrismMut<Optics, Image> + TryPrismRef<Optics, Image>
+    {
+    }
+
     /// the movable version of Prism
     pub trait Prism<Optics, Image>: PrismMut<Optics, Image> + Traversal<Optics, Image> {
         fn preview(self, optics: Optics) -> Option<Image>
         where
             Self: Sized;
     }
+
+    /// the version of Prism telling why it previews nothing,
+    /// implemented for every `Prism` and `TryPrismRef`
+    pub trait TryPrism<Optics, Image>: Prism<Optics, Image> + TryPrismRef<Optics, Image> {
+        /// previews the focus by value, or tells which optic misses at which depth.
+        /// `self` is gone after `preview`, so the miss is explained beforehand
+        /// if `preview_ref` has nothing, and it's reported only if `preview` has nothing too
+        fn try_preview(self, optics: Optics) -> Result<Image, PathMiss>
+        where
+            Optics: Clone,
+            Self: Sized,
+        {
+            if PrismRef::<Optics, Image>::preview_ref(&self, optics.clone()).is_some() {
+                return self.preview(optics).ok_or_else(no_focus);
+            }
+            let miss = TryPrismRef::<Optics, Image>::miss_ref(
+                &self,
+                optics.clone(),
+                &mut OpticPath::new(),
+            );
+            match self.preview(optics) {
+                Some(x) => Ok(x),
+                None => Err(miss.unwrap_or_else(no_focus)),
+            }
+        }
+    }
+
+    impl<Optics, Image, T> TryPrism<Optics, Image> for T where
+        T: Prism<Optics, Image> + TryPrismRef<Optics, Image>
+    {
+    }
 }
 
 /// # Lens
@@ -115,5 +445,562 @@ pub(crate) mod lens {
         fn view(self, optics: Optics) -> Image
         where
             Self: Sized;
+
+        /// rebuild the structure with the focus replaced by `value`,
+        /// the `set` of a lens moving `value` in without cloning it
+        fn put(self, optics: Optics, value: Image) -> Self
+        where
+            Self: Sized,
+        {
+            let mut value = Some(value);
+            self.over(optics, move |_| {
+                value.take().expect("a lens has exactly one focus")
+            })
+        }
+    }
+}
+
+/// # Fold
+///
+/// Read-only queries over the foci of a `TraversalRef`, stopping as early as possible.
+/// Implemented for every `TraversalRef`.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// let x = (1, vec![Some((2, 3)), None, Some((4, 5))]);
+/// assert!(x.has(optics!(_1._mapped.Some)));
+/// assert_eq!(x.first_of(optics!(_1._mapped.Some._1)), Some(&3));
+/// assert_eq!(x.length_of(optics!(_1._mapped.Some)), 2);
+/// assert_eq!(x.sum_of::<i32>(optics!(_1._mapped.Some._both)), 14);
+/// assert_eq!(x.max_of(optics!(_1._mapped.Some._0)), Some(&4));
+/// ```
+pub mod fold {
+    use crate::*;
+    use std::cmp::Ordering;
+    use std::ops::{AddAssign, ControlFlow};
+
+    pub trait Fold<Optics, Image: ?Sized>: TraversalRef<Optics, Image> {
+        /// whether there is at least one focus
+        fn has(&self, optics: Optics) -> bool {
+            self.first_of(optics).is_some()
+        }
+
+        /// the first focus
+        fn first_of(&self, optics: Optics) -> Option<&Image> {
+            self.find_of(optics, |_| true)
+        }
+
+        /// the first focus satisfying `pred`
+        fn find_of<P>(&self, optics: Optics, mut pred: P) -> Option<&Image>
+        where
+            P: FnMut(&Image) -> bool,
+        {
+            match self.try_for_each_ref(optics, |x| {
+                if pred(x) {
+                    ControlFlow::Break(x)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            }) {
+                ControlFlow::Break(x) => Some(x),
+                ControlFlow::Continue(()) => None,
+            }
+        }
+
+        /// whether any focus satisfies `pred`
+        fn any_of<P>(&self, optics: Optics, pred: P) -> bool
+        where
+            P: FnMut(&Image) -> bool,
+        {
+            self.find_of(optics, pred).is_some()
+        }
+
+        /// whether every focus satisfies `pred`
+        fn all_of<P>(&self, optics: Optics, mut pred: P) -> bool
+        where
+            P: FnMut(&Image) -> bool,
+        {
+            self.find_of(optics, |x| !pred(x)).is_none()
+        }
+
+        /// the number of foci
+        fn length_of(&self, optics: Optics) -> usize {
+            let mut len = 0;
+            self.for_each_ref(optics, |_| len += 1);
+            len
+        }
+
+        /// add up all foci, starting from `S::default()`
+        fn sum_of<'a, S>(&'a self, optics: Optics) -> S
+        where
+            S: Default + AddAssign<&'a Image>,
+            Image: 'a,
+        {
+            let mut sum = S::default();
+            self.for_each_ref(optics, |x| sum += x);
+            sum
+        }
+
+        /// the greatest focus, the last one if several are equally great
+        fn max_of(&self, optics: Optics) -> Option<&Image>
+        where
+            Image: Ord,
+        {
+            self.max_of_by(optics, Ord::cmp)
+        }
+
+        /// the greatest focus with respect to `compare`, the last one if several are equally great
+        fn max_of_by<C>(&self, optics: Optics, mut compare: C) -> Option<&Image>
+        where
+            C: FnMut(&Image, &Image) -> Ordering,
+        {
+            let mut max: Option<&Image> = None;
+            self.for_each_ref(optics, |x| match max {
+                Some(m) if compare(m, x) == Ordering::Greater => {}
+                _ => max = Some(x),
+            });
+            max
+        }
+    }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> Fold<Optics, Image> for T where T: TraversalRef<Optics, Image> {}
+}
+
+/// # Owned Optics
+///
+/// Traits moving the foci out of the source as owned values,
+/// for the optics whose foci aren't stored in the source as they are.
+/// The text optics `_lines`, `_words`, `_char_strs`, `_prefixed` and `_suffixed`
+/// move the pieces of a `String` out as `String`, `_chars` moves its characters out as `char`,
+/// and the codecs `_parsed`, `_utf8`, `_hex` and `_base64` move the decoded values out.
+/// `_mapped` moves the items of the sets and `BinaryHeap` out,
+/// which can't be written in place without breaking their hashes or order.
+/// `over_owned` rebuilds the source from the rewritten foci.
+/// The derived field and variant optics and `_mapped` forward both traits to the rest optics.
+///
+/// These optics can't implement the traits from `TraversalRef` to `Prism`,
+/// whose foci are borrowed from the source:
+/// the borrowed pieces of a `String` are `str` slices,
+/// and there is nothing to borrow for the decoded value but a temporary,
+/// which is lent by the scoped traits instead.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// let log = String::from("INFO start\nWARN slow");
+/// let log = log.over_owned(optics!(_lines._prefixed("WARN ")), |s| s.to_uppercase());
+/// assert_eq!(log, "INFO start\nWARN SLOW");
+/// assert_eq!(log.traverse_owned(optics!(_lines._words)), vec!["INFO", "start", "WARN", "SLOW"]);
+///
+/// assert_eq!("8080".preview_owned(optics!(_parsed::<u16>)), Some(8080));
+/// let port = String::from("8080").over_owned(optics!(_parsed::<u16>), |i| i + 1);
+/// assert_eq!(port, "8081");
+/// let x = (String::from("8080"), 1).over_owned(optics!(_0._parsed::<u16>), |i| i + 1);
+/// assert_eq!(x, (String::from("8081"), 1));
+/// ```
+pub mod owned {
+    use std::ops::ControlFlow;
+
+    pub trait TraversalOwned<Optics, Image> {
+        /// move every focus out in order, dropping the rest as soon as `f` breaks
+        fn try_for_each_owned<B, F>(self, optics: Optics, f: F) -> ControlFlow<B>
+        where
+            F: FnMut(Image) -> ControlFlow<B>,
+            Self: Sized;
+
+        fn traverse_owned(self, optics: Optics) -> Vec<Image>
+        where
+            Self: Sized,
+        {
+            let mut vec = vec![];
+            let _ = self.try_for_each_owned(optics, |x| -> ControlFlow<()> {
+                vec.push(x);
+                ControlFlow::Continue(())
+            });
+            vec
+        }
+
+        /// rebuild the source with `f` applied to every focus
+        fn over_owned<F>(self, optics: Optics, f: F) -> Self
+        where
+            F: FnMut(Image) -> Image,
+            Self: Sized;
+    }
+
+    /// the by-value preview moving the decoded value out,
+    /// also implemented for `&str`, which can't be rebuilt by `over_owned`
+    pub trait PrismOwned<Optics, Image> {
+        fn preview_owned(self, optics: Optics) -> Option<Image>
+        where
+            Self: Sized;
+    }
+}
+
+/// # Parallel Traversal
+///
+/// A trait running the traversal over the top-level collection in parallel by `rayon`,
+/// available with the feature `rayon`.
+/// The rest optics still visit each element sequentially.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// let mut x = vec![(1, 'a'), (2, 'b'), (3, 'c')];
+/// x.par_for_each_mut(optics!(_mapped._0), |i| *i *= 2);
+/// assert_eq!(x.par_traverse_mut(optics!(_mapped._0)), vec![&mut 2, &mut 4, &mut 6]);
+/// ```
+#[cfg(feature = "rayon")]
+pub mod par {
+    use crate::*;
+
+    /// the parallel version of TraversalMut
+    pub trait ParTraversalMut<Optics, Image: ?Sized>: TraversalMut<Optics, Image> {
+        /// visit every focus in parallel, without collecting them
+        fn par_for_each_mut<F>(&mut self, optics: Optics, f: F)
+        where
+            F: Fn(&mut Image) + Send + Sync;
+
+        /// collect every focus in order, visiting the collection in parallel
+        fn par_traverse_mut(&mut self, optics: Optics) -> Vec<&mut Image>
+        where
+            Image: Send;
+    }
+}
+
+/// # Scoped Access
+///
+/// Traits reaching the data behind `RefCell`, `Mutex` and `RwLock` by the lock optics
+/// `_borrow`, `_lock`, `_read` and `_write`, which hold the guard while the closure runs.
+/// The optics before a lock should be the generated optics in `lens_rs::optics`,
+/// or the ones built from closures by `lens_fn`, `prism_fn` and `_to`,
+/// and the data behind a lock should be `'static`.
+///
+/// `with_view_mut` and `with_each_mut` take the root by `&mut` even if the first step is a lock,
+/// so a lock shared by `&Rc<RefCell<_>>` goes through a clone of the `Rc`,
+/// and a lock shared by `&RefCell<_>` is borrowed by hand.
+///
+/// The codecs `_parsed`, `_utf8`, `_hex` and `_base64` work like the locks,
+/// decoding the data into a temporary while the closure runs,
+/// and encoding it back afterwards for `with_each_mut`.
+/// They move the decoded value out by the traits in `owned`.
+/// The getter `_to_value` goes on with the value computed by its closure, for reading only.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// let mut x = (Rc::new(RefCell::new((1, 2))), vec![Arc::new(Mutex::new(Some(3)))]);
+/// assert_eq!(x.with_view_ref(optics!(_0._borrow._1), |i| *i), 2);
+/// x.with_each_mut(optics!(_1._mapped._lock.Some), |i| *i += 1);
+///
+/// let mut port = String::from("8080");
+/// port.with_each_mut(optics!(_parsed::<u16>), |i| *i += 1);
+/// assert_eq!(port, "8081");
+///
+/// let shared = &x.0;
+/// Rc::clone(shared).with_view_mut(optics!(_borrow._0), |i| *i += 1);
+/// shared.borrow_mut().1 = 3;
+/// ```
+pub mod scoped {
+    use crate::*;
+
+    pub use lens_rs_generator::{NoLock, SplitAtLock};
+
+    /// the immutable scoped version of Traversal
+    pub trait ScopedTraversalRef<Optics, Image: ?Sized> {
+        /// visit every focus in order, holding the guards while visiting
+        fn with_each_ref<F>(&self, optics: Optics, f: F)
+        where
+            F: FnMut(&Image);
+    }
+
+    /// the mutable scoped version of Traversal
+    pub trait ScopedTraversalMut<Optics, Image: ?Sized> {
+        /// visit every focus in order, holding the guards while visiting
+        fn with_each_mut<F>(&mut self, optics: Optics, f: F)
+        where
+            F: FnMut(&mut Image);
+    }
+
+    /// the immutable scoped version of Lens
+    pub trait ScopedLensRef<Optics, Image: ?Sized> {
+        /// run `f` with the focus, holding the guards while running
+        fn with_view_ref<R, F>(&self, optics: Optics, f: F) -> R
+        where
+            F: FnOnce(&Image) -> R;
+    }
+
+    /// the mutable scoped version of Lens
+    pub trait ScopedLensMut<Optics, Image: ?Sized> {
+        /// run `f` with the focus, holding the guards while running
+        fn with_view_mut<R, F>(&mut self, optics: Optics, f: F) -> R
+        where
+            F: FnOnce(&mut Image) -> R;
+    }
+
+    /// the marker of going on behind a lock by a traversal
+    pub enum ByTraversal {}
+
+    /// the marker of going on behind a lock by a lens
+    pub enum ByLens {}
+
+    /// the rest of the optics behind a lock
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Locked<Optics>(pub Optics);
+
+    /// a lock going on with the rest optics while holding the guard
+    pub trait LockRef<Optics, Image: ?Sized, By> {
+        fn lock_ref(&self, optics: Optics, f: &mut dyn FnMut(&Image));
+    }
+
+    /// a lock going on with the rest optics while holding the exclusive guard
+    ///
+    /// It takes `&mut self` though the locks only need `&self`,
+    /// since the codecs write the source back after the rest optics.
+    pub trait LockMut<Optics, Image: ?Sized, By> {
+        fn lock_mut(&mut self, optics: Optics, f: &mut dyn FnMut(&mut Image));
+    }
+
+    /// to go on from where the optics before the first lock stop
+    pub trait Resume<Image: ?Sized, By> {
+        type FocusRef: ?Sized;
+        type FocusMut: ?Sized;
+
+        fn resume_ref(self, focus: &Self::FocusRef, f: &mut dyn FnMut(&Image));
+
+        fn resume_mut(self, focus: &mut Self::FocusMut, f: &mut dyn FnMut(&mut Image));
+    }
+
+    impl<Image: ?Sized, By> Resume<Image, By> for NoLock {
+        type FocusRef = Image;
+        type FocusMut = Image;
+
+        #[inline]
+        fn resume_ref(self, focus: &Image, f: &mut dyn FnMut(&Image)) {
+            f(focus)
+        }
+
+        #[inline]
+        fn resume_mut(self, focus: &mut Image, f: &mut dyn FnMut(&mut Image)) {
+            f(focus)
+        }
+    }
+
+    impl<Optics, Image: ?Sized, By> Resume<Image, By> for Locked<Optics> {
+        type FocusRef = dyn LockRef<Optics, Image, By>;
+        type FocusMut = dyn LockMut<Optics, Image, By>;
+
+        #[inline]
+        fn resume_ref(self, focus: &Self::FocusRef, f: &mut dyn FnMut(&Image)) {
+            focus.lock_ref(self.0, f)
+        }
+
+        #[inline]
+        fn resume_mut(self, focus: &mut Self::FocusMut, f: &mut dyn FnMut(&mut Image)) {
+            focus.lock_mut(self.0, f)
+        }
+    }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> ScopedTraversalRef<Optics, Image> for T
+    where
+        Optics: SplitAtLock,
+        Optics::Rest: Resume<Image, ByTraversal> + Clone,
+        T: TraversalRef<Optics::Prefix, <Optics::Rest as Resume<Image, ByTraversal>>::FocusRef>,
+    {
+        fn with_each_ref<F>(&self, optics: Optics, mut f: F)
+        where
+            F: FnMut(&Image),
+        {
+            let (prefix, rest) = optics.split_at_lock();
+            self.for_each_ref(prefix, |focus| rest.clone().resume_ref(focus, &mut f))
+        }
+    }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> ScopedTraversalMut<Optics, Image> for T
+    where
+        Optics: SplitAtLock,
+        Optics::Rest: Resume<Image, ByTraversal> + Clone,
+        T: TraversalMut<Optics::Prefix, <Optics::Rest as Resume<Image, ByTraversal>>::FocusMut>,
+    {
+        fn with_each_mut<F>(&mut self, optics: Optics, mut f: F)
+        where
+            F: FnMut(&mut Image),
+        {
+            let (prefix, rest) = optics.split_at_lock();
+            self.for_each_mut(prefix, |focus| rest.clone().resume_mut(focus, &mut f))
+        }
     }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> ScopedLensRef<Optics, Image> for T
+    where
+        Optics: SplitAtLock,
+        Optics::Rest: Resume<Image, ByLens>,
+        T: LensRef<Optics::Prefix, <Optics::Rest as Resume<Image, ByLens>>::FocusRef>,
+    {
+        fn with_view_ref<R, F>(&self, optics: Optics, f: F) -> R
+        where
+            F: FnOnce(&Image) -> R,
+        {
+            let (prefix, rest) = optics.split_at_lock();
+            let (mut f, mut r) = (Some(f), None);
+            rest.resume_ref(self.view_ref(prefix), &mut |x| r = f.take().map(|f| f(x)));
+            r.expect("the lens should focus on exactly one value")
+        }
+    }
+
+    impl<Optics, Image: ?Sized, T: ?Sized> ScopedLensMut<Optics, Image> for T
+    where
+        Optics: SplitAtLock,
+        Optics::Rest: Resume<Image, ByLens>,
+        T: LensMut<Optics::Prefix, <Optics::Rest as Resume<Image, ByLens>>::FocusMut>,
+    {
+        fn with_view_mut<R, F>(&mut self, optics: Optics, f: F) -> R
+        where
+            F: FnOnce(&mut Image) -> R,
+        {
+            let (prefix, rest) = optics.split_at_lock();
+            let (mut f, mut r) = (Some(f), None);
+            rest.resume_mut(self.view_mut(prefix), &mut |x| r = f.take().map(|f| f(x)));
+            r.expect("the lens should focus on exactly one value")
+        }
+    }
+}
+
+/// # Split Borrows
+///
+/// Traits viewing several disjoint paths mutably at once,
+/// where the paths are made of the field and variant optics derived by `Lens` and `Prism`,
+/// or those of the tuples, `Option` and `Result`.
+/// The paths overlapping or going into the variants of an enum in common,
+/// even the different variants, are rejected when compiling.
+///
+/// The check is an assertion in a constant evaluated after monomorphization,
+/// so `cargo check` accepts the overlapping paths, and only `cargo build` rejects them.
+///
+/// ## Example
+/// ```ignore
+/// use lens_rs::*;
+/// let mut x = (1, (2, 'a'), Some(3));
+/// let (a, b) = x.view_mut_many((optics!(_0), optics!(_1._0)));
+/// std::mem::swap(a, b);
+/// let (c, d) = x.preview_mut_many((optics!(_1._1), optics!(_2.Some)));
+/// assert_eq!((c, d), (Some(&mut 'a'), Some(&mut 3)));
+/// ```
+///
+/// The overlapping paths are rejected, since `_1` covers `_1._0`:
+///
+/// ```compile_fail
+/// use lens_rs::*;
+/// let mut x = (1, (2, 'a'));
+/// x.view_mut_many((optics!(_1), optics!(_1._0)));
+/// ```
+///
+/// So are the sibling variants, since `Err(())` is stored in the niche of the `bool` of `Ok`,
+/// and matching `Err` would read the payload of `Ok` borrowed mutably:
+///
+/// ```compile_fail
+/// use lens_rs::*;
+/// let mut x = (1, Ok::<bool, ()>(true));
+/// x.preview_mut_many((optics!(_1.Ok), optics!(_1.Err)));
+/// ```
+pub mod split {
+    use crate::*;
+
+    pub use lens_rs_generator::{Segments, SplitStaticPath, StaticPath};
+
+    /// the optics projecting the pointer to a field or a payload, implemented by the derive macros
+    ///
+    /// # Safety
+    ///
+    /// `project_mut` should only project `this` to the place named by the optics,
+    /// without accessing any other place but the discriminant of the enum,
+    /// so that the disjoint paths never alias.
+    pub unsafe trait ProjectMut<Optics> {
+        /// the type of the place, fixed by the path
+        type Image: ?Sized;
+
+        /// # Safety
+        ///
+        /// `this` should be valid for reads and writes.
+        unsafe fn project_mut(this: *mut Self, optics: Optics) -> Option<*mut Self::Image>;
+    }
+
+    unsafe impl<T: ?Sized> ProjectMut<__> for T {
+        type Image = T;
+
+        #[inline]
+        unsafe fn project_mut(this: *mut T, _optics: __) -> Option<*mut T> {
+            Some(this)
+        }
+    }
+
+    /// the mutable version of Lens focusing on several disjoint paths at once
+    pub trait LensMutMany<'a, Optics, Images> {
+        fn view_mut_many(&'a mut self, optics: Optics) -> Images;
+    }
+
+    /// the mutable version of Prism focusing on several disjoint paths at once
+    pub trait PrismMutMany<'a, Optics, Images> {
+        fn preview_mut_many(&'a mut self, optics: Optics) -> Images;
+    }
+
+    // evaluated only when `view_mut_many` or `preview_mut_many` is monomorphized,
+    // which `cargo check` doesn't do
+    pub(crate) trait Disjoint {
+        const DISJOINT: ();
+    }
+
+    macro_rules! impl_many {
+        ($($optic:ident $image:ident $o:ident),*) => {
+            impl<$($optic: StaticPath),*> Disjoint for ($($optic,)*) {
+                const DISJOINT: () = assert!(
+                    Segments::are_disjoint(&[$($optic::PATH),*]),
+                    "the paths should be disjoint, parting at fields before going into any variant"
+                );
+            }
+
+            impl<'a, T: ?Sized, $($optic, $image: ?Sized + 'a),*> LensMutMany<'a, ($($optic,)*), ($(&'a mut $image,)*)> for T
+            where
+                $($optic: StaticPath, T: LensMut<$optic, $image> + ProjectMut<$optic, Image = $image>,)*
+            {
+                fn view_mut_many(&'a mut self, optics: ($($optic,)*)) -> ($(&'a mut $image,)*) {
+                    let () = <($($optic,)*) as Disjoint>::DISJOINT;
+                    let this: *mut T = self;
+                    let ($($o,)*) = optics;
+                    // SAFETY: the disjoint paths project to the places never aliasing,
+                    // and the lenses always find their places
+                    unsafe {
+                        ($(
+                            &mut *<T as ProjectMut<$optic>>::project_mut(this, $o)
+                                .expect("the lens should focus on exactly one value"),
+                        )*)
+                    }
+                }
+            }
+
+            impl<'a, T: ?Sized, $($optic, $image: ?Sized + 'a),*> PrismMutMany<'a, ($($optic,)*), ($(Option<&'a mut $image>,)*)> for T
+            where
+                $($optic: StaticPath, T: ProjectMut<$optic, Image = $image>,)*
+            {
+                fn preview_mut_many(&'a mut self, optics: ($($optic,)*)) -> ($(Option<&'a mut $image>,)*) {
+                    let () = <($($optic,)*) as Disjoint>::DISJOINT;
+                    let this: *mut T = self;
+                    let ($($o,)*) = optics;
+                    // SAFETY: the disjoint paths project to the places never aliasing
+                    unsafe {
+                        ($(
+                            <T as ProjectMut<$optic>>::project_mut(this, $o).map(|x| &mut *x),
+                        )*)
+                    }
+                }
+            }
+        };
+    }
+
+    impl_many!(A I a);
+    impl_many!(A I a, B J b);
+    impl_many!(A I a, B J b, C K c);
+    impl_many!(A I a, B J b, C K c, D L d);
+    impl_many!(A I a, B J b, C K c, D L d, E M e);
+    impl_many!(A I a, B J b, C K c, D L d, E M e, F N f);
 }
@@ -75,6 +75,19 @@ fn field_optic_attr(var: &syn::Field) -> Option<syn::Attribute> {
         .cloned()
 }
 
+fn type_with_path_attr(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.path
+                .is_ident(&syn::Ident::new("optic", Span::call_site()))
+        })
+        .any(|attr| match attr.parse_args::<syn::Ident>() {
+            Ok(id) if id == "path" => true,
+            _ => panic!("only allow #[optic(path)] on the type"),
+        })
+}
+
 #[proc_macro_derive(Review, attributes(optic))]
 pub fn derive_review(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -184,7 +197,33 @@ pub fn derive_prism(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         _ => panic!("union and struct can't derive the `Prism`"),
     };
 
-    TokenStream::from(prisms)
+    let reflect = match derive_input.data.clone() {
+        Data::Enum(e) if type_with_path_attr(&derive_input.attrs) => {
+            let (var_names, field_tys) = e
+                .variants
+                .iter()
+                .filter(|var| variant_with_optic_attr(var))
+                .filter_map(|var| match var.fields.clone() {
+                    Fields::Unnamed(fs) if fs.unnamed.len() == 1 => {
+                        Some((var.ident.clone(), fs.unnamed[0].ty.clone()))
+                    }
+                    _ => None,
+                })
+                .unzip();
+            impl_reflect4enum(
+                derive_input.ident.clone(),
+                derive_input.generics.clone(),
+                var_names,
+                field_tys,
+            )
+        }
+        _ => quote! {},
+    };
+
+    TokenStream::from(quote! {
+        #prisms
+        #reflect
+    })
 }
 
 #[proc_macro_derive(Lens, attributes(optic))]
@@ -303,7 +342,74 @@ pub fn derive_lens(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         _ => panic!("can only derive `Lens` for struct"),
     };
 
-    TokenStream::from(lens)
+    let reflect = match derive_input.data.clone() {
+        Data::Struct(syn::DataStruct { fields, .. })
+            if type_with_path_attr(&derive_input.attrs) =>
+        {
+            let (members, field_tys) = fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| field_with_optic_attr(f))
+                .map(|(i, f)| match f.ident.clone() {
+                    Some(ident) => (syn::Member::Named(ident), f.ty.clone()),
+                    None => (syn::Member::Unnamed(syn::Index::from(i)), f.ty.clone()),
+                })
+                .unzip();
+            impl_reflect4struct(
+                derive_input.ident.clone(),
+                derive_input.generics.clone(),
+                members,
+                field_tys,
+            )
+        }
+        _ => quote! {},
+    };
+
+    TokenStream::from(quote! {
+        #lens
+        #reflect
+    })
+}
+
+#[proc_macro_derive(Iso, attributes(optic))]
+pub fn derive_iso(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match derive_input.data.clone() {
+        Data::Struct(syn::DataStruct { fields, .. }) => fields,
+        _ => panic!("can only derive `Iso` for struct"),
+    };
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match f.ident.clone() {
+            Some(ident) => syn::Member::Named(ident),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        })
+        .collect::<Vec<_>>();
+    let field_tys = fields.iter().map(|f| f.ty.clone()).collect::<Vec<_>>();
+
+    let newtype = if fields.len() == 1 {
+        impl_newtype(
+            derive_input.ident.clone(),
+            derive_input.generics.clone(),
+            members[0].clone(),
+            field_tys[0].clone(),
+        )
+    } else {
+        quote! {}
+    };
+    let tuple_repr = impl_iso4tuple_repr(
+        derive_input.ident.clone(),
+        derive_input.generics.clone(),
+        members,
+        field_tys,
+    );
+
+    TokenStream::from(quote! {
+        #newtype
+        #tuple_repr
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -314,6 +420,24 @@ enum AnOpticExpr {
         _bracket_token: syn::token::Bracket,
         ix: syn::Expr,
     },
+    At {
+        _bracket_token: syn::token::Bracket,
+        _question_token: Token![?],
+        ix: syn::Expr,
+    },
+    Call {
+        id: syn::Ident,
+        _paren_token: syn::token::Paren,
+        args: proc_macro2::TokenStream,
+    },
+    Generic {
+        id: syn::Ident,
+        generics: syn::AngleBracketedGenericArguments,
+    },
+    Branch {
+        _brace_token: syn::token::Brace,
+        branches: Punctuated<OpticsPathExpr, Token![,]>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -323,21 +447,65 @@ struct OpticsPathExpr {
 
 impl Parse for AnOpticExpr {
     fn parse(input: ParseStream) -> Result<Self> {
-        if input.peek(syn::Ident) && !input.peek2(Token![::]) {
+        if input.peek(syn::Ident) && input.peek2(syn::token::Paren) {
+            let content;
+            Ok(AnOpticExpr::Call {
+                id: input.parse()?,
+                _paren_token: parenthesized!(content in input),
+                args: content.parse()?,
+            })
+        } else if input.peek(syn::Ident) && !input.peek2(Token![::]) {
             Ok(AnOpticExpr::Default(input.parse()?))
+        } else if input.peek(syn::Ident) && is_turbofish(&input.fork()) {
+            Ok(AnOpticExpr::Generic {
+                id: input.parse()?,
+                generics: input.parse()?,
+            })
+        } else if input.peek(syn::token::Brace) {
+            let content;
+            let _brace_token = syn::braced!(content in input);
+            let branches = Punctuated::parse_terminated_with(&content, |branch| {
+                Ok(OpticsPathExpr {
+                    path: Punctuated::parse_separated_nonempty(branch)?,
+                })
+            })?;
+            if branches.is_empty() {
+                return Err(syn::Error::new(
+                    _brace_token.span,
+                    "expected at least one branch",
+                ));
+            }
+            Ok(AnOpticExpr::Branch {
+                _brace_token,
+                branches,
+            })
         } else if input.peek(syn::token::Bracket) {
             let content;
             let _bracket_token = syn::bracketed!(content in input);
-            Ok(AnOpticExpr::Ix {
-                _bracket_token,
-                ix: content.parse()?,
-            })
+            if content.peek(Token![?]) {
+                Ok(AnOpticExpr::At {
+                    _bracket_token,
+                    _question_token: content.parse()?,
+                    ix: content.parse()?,
+                })
+            } else {
+                Ok(AnOpticExpr::Ix {
+                    _bracket_token,
+                    ix: content.parse()?,
+                })
+            }
         } else {
             Ok(AnOpticExpr::Custom(input.parse()?))
         }
     }
 }
 
+fn is_turbofish(input: ParseStream) -> bool {
+    input.parse::<syn::Ident>().is_ok()
+        && input.parse::<Token![::]>().is_ok()
+        && input.peek(Token![<])
+}
+
 impl Parse for OpticsPathExpr {
     fn parse(input: ParseStream) -> Result<Self> {
         Ok(OpticsPathExpr {
@@ -346,18 +514,131 @@ impl Parse for OpticsPathExpr {
     }
 }
 
+// the modifiers change the foci of the whole optics before or after them
+const MODIFIERS: [&str; 4] = ["_taking", "_dropping", "_element", "_backwards"];
+
+fn modifier(opt: &AnOpticExpr) -> Option<&syn::Ident> {
+    match opt {
+        AnOpticExpr::Default(id) | AnOpticExpr::Call { id, .. } => {
+            Some(id).filter(|id| MODIFIERS.iter().any(|m| id == m))
+        }
+        _ => None,
+    }
+}
+
+fn reject_modifiers(path: &OpticsPathExpr) -> Result<()> {
+    for opt in path.path.iter() {
+        if let Some(id) = modifier(opt) {
+            return Err(syn::Error::new(
+                id.span(),
+                format!(
+                    "`{}` modifies the foci of the whole optics, so it can't go in a branch",
+                    id
+                ),
+            ));
+        }
+        if let AnOpticExpr::Branch { branches, .. } = opt {
+            branches.iter().try_for_each(reject_modifiers)?;
+        }
+    }
+    Ok(())
+}
+
+// `_or` ends the path, since the optics after it have no image in common to go on from
+fn reject_after_or(path: &OpticsPathExpr, followed: bool) -> Result<()> {
+    let opts = path.path.iter().collect::<Vec<_>>();
+    for (i, opt) in opts.iter().enumerate() {
+        let followed = followed || opts[i + 1..].iter().any(|opt| modifier(opt).is_none());
+        match opt {
+            AnOpticExpr::Call { id, .. } if id == "_or" && followed => {
+                return Err(syn::Error::new(
+                    id.span(),
+                    "`_or` should end the path, put the rest optics into both of its optics, \
+                     as `_or(optics!(a.rest), optics!(b.rest))`",
+                ));
+            }
+            AnOpticExpr::Branch { branches, .. } => {
+                branches
+                    .iter()
+                    .try_for_each(|branch| reject_after_or(branch, followed))?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+// a modifier modifies the foci of the optics before it, or of the rest optics if it leads the path,
+// so the trailing modifiers go to the front in reverse order, to wrap the leading ones
+fn hoist_modifiers(path: OpticsPathExpr) -> Result<OpticsPathExpr> {
+    reject_after_or(&path, false)?;
+    for opt in path.path.iter() {
+        if let AnOpticExpr::Branch { branches, .. } = opt {
+            branches.iter().try_for_each(reject_modifiers)?;
+        }
+    }
+    let mut opts = path.path.into_iter().collect::<Vec<_>>();
+    let leading = opts
+        .iter()
+        .take_while(|opt| modifier(opt).is_some())
+        .count();
+    let trailing = opts[leading..]
+        .iter()
+        .rev()
+        .take_while(|opt| modifier(opt).is_some())
+        .count();
+    if let Some(id) = opts[leading..opts.len() - trailing]
+        .iter()
+        .find_map(modifier)
+    {
+        return Err(syn::Error::new(
+            id.span(),
+            format!(
+                "`{}` modifies the foci of the whole optics before it, so it should lead or end the path",
+                id
+            ),
+        ));
+    }
+    let modifiers = opts.split_off(opts.len() - trailing);
+    Ok(OpticsPathExpr {
+        path: modifiers.into_iter().rev().chain(opts).collect(),
+    })
+}
+
 #[proc_macro]
 pub fn optics(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let path = parse_macro_input!(input as OpticsPathExpr);
+    match hoist_modifiers(path) {
+        Ok(path) => optics_expr(path, quote! { lens_rs::optics::__ }).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn optics_expr(path: OpticsPathExpr, rest: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
     path.path
         .into_iter()
         .rev()
-        .fold(quote! { lens_rs::optics::__ }, |opts, opt| match opt {
+        .fold(rest, |opts, opt| match opt {
             AnOpticExpr::Default(id) => quote! { lens_rs::optics::#id(#opts) },
             AnOpticExpr::Custom(p) => quote! { #p(#opts) },
             AnOpticExpr::Ix { ix, .. } => quote! { lens_rs::optics::_ix(#opts, #ix) },
+            AnOpticExpr::At { ix, .. } => quote! { lens_rs::optics::_at(#opts, #ix) },
+            AnOpticExpr::Call { id, args, .. } => quote! { lens_rs::optics::#id(#opts, #args) },
+            AnOpticExpr::Generic { id, generics } => {
+                let args = generics.args.iter();
+                quote! { lens_rs::optics::#id::<#(#args,)* _>(#opts) }
+            }
+            AnOpticExpr::Branch { branches, .. } => {
+                // the rest optics are evaluated once, and cloned into every branch
+                let branches = branches
+                    .into_iter()
+                    .map(|branch| optics_expr(branch, quote! { __rest.clone() }));
+                quote! {{
+                    let __rest = #opts;
+                    lens_rs::optics::_branch((#(#branches,)*))
+                }}
+            }
         })
-        .into()
 }
 
 #[derive(Clone, Debug)]
@@ -404,15 +685,37 @@ impl Parse for OpticsPathType {
 #[allow(non_snake_case)]
 pub fn Optics(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let path = parse_macro_input!(input as OpticsPathExpr);
+    match hoist_modifiers(path) {
+        Ok(path) => optics_type(path, quote! { lens_rs::optics::__ }).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn optics_type(path: OpticsPathExpr, rest: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
     path.path
         .into_iter()
         .rev()
-        .fold(quote! { lens_rs::optics::__ }, |opts, opt| match opt {
+        .fold(rest, |opts, opt| match opt {
             AnOpticExpr::Default(id) => quote! { lens_rs::optics::#id<#opts> },
             AnOpticExpr::Custom(p) => quote! { #p::<#opts> },
             AnOpticExpr::Ix { ix, .. } => quote! { lens_rs::optics::_ix<#ix, #opts> },
+            AnOpticExpr::At { ix, .. } => quote! { lens_rs::optics::_at<#ix, #opts> },
+            // the arguments of the modifiers are counts, not types
+            AnOpticExpr::Call { id, .. } if MODIFIERS.iter().any(|m| id == m) => {
+                quote! { lens_rs::optics::#id<#opts> }
+            }
+            AnOpticExpr::Call { id, args, .. } => quote! { lens_rs::optics::#id<#args, #opts> },
+            AnOpticExpr::Generic { id, generics } => {
+                let args = generics.args.iter();
+                quote! { lens_rs::optics::#id<#(#args,)* #opts> }
+            }
+            AnOpticExpr::Branch { branches, .. } => {
+                let branches = branches
+                    .into_iter()
+                    .map(|branch| optics_type(branch, opts.clone()));
+                quote! { lens_rs::optics::_branch<(#(#branches,)*)> }
+            }
         })
-        .into()
 }
 
 #[proc_macro]
@@ -80,11 +80,18 @@ pub fn impl_mut4variant(
         var_name.clone(),
         field_ty.clone(),
     );
-    let prism_impl = impl_prism_mut4variant(ty_name, generic, var_name, field_ty);
+    let prism_impl = impl_prism_mut4variant(
+        ty_name.clone(),
+        generic.clone(),
+        var_name.clone(),
+        field_ty.clone(),
+    );
+    let project_impl = impl_project4variant(ty_name, generic, var_name, field_ty);
     quote! {
         #rf
         #traversal_impl
         #prism_impl
+        #project_impl
     }
 }
 
@@ -107,11 +114,18 @@ pub fn impl_mv4variant(
         var_name.clone(),
         field_ty.clone(),
     );
-    let prism_impl = impl_prism4variant(ty_name, generic, var_name, field_ty);
+    let prism_impl = impl_prism4variant(
+        ty_name.clone(),
+        generic.clone(),
+        var_name.clone(),
+        field_ty.clone(),
+    );
+    let owned_impl = impl_owned4variant(ty_name, generic, var_name, field_ty);
     quote! {
         #mt
         #traversal_impl
         #prism_impl
+        #owned_impl
     }
 }
 
@@ -168,13 +182,20 @@ pub fn impl_mut4field(
         field_name.clone(),
         field_ty.clone(),
     );
-    let lens_impl = impl_lens_mut4field(ty_name, generic, field_name, field_ty);
+    let lens_impl = impl_lens_mut4field(
+        ty_name.clone(),
+        generic.clone(),
+        field_name.clone(),
+        field_ty.clone(),
+    );
+    let project_impl = impl_project4field(ty_name, generic, field_name, field_ty);
 
     quote! {
         #rf
         #traversal_impl
         #prism_impl
         #lens_impl
+        #project_impl
     }
 }
 
@@ -203,13 +224,20 @@ pub fn impl_mv4field(
         field_name.clone(),
         field_ty.clone(),
     );
-    let lens_impl = impl_lens4field(ty_name, generic, field_name, field_ty);
+    let lens_impl = impl_lens4field(
+        ty_name.clone(),
+        generic.clone(),
+        field_name.clone(),
+        field_ty.clone(),
+    );
+    let owned_impl = impl_owned4field(ty_name, generic, field_name, field_ty);
 
     quote! {
         #mt
         #traversal_impl
         #prism_impl
         #lens_impl
+        #owned_impl
     }
 }
 
@@ -266,13 +294,20 @@ pub fn impl_mut4index(
         field_name.clone(),
         field_ty.clone(),
     );
-    let lens_impl = impl_lens_mut4index(ty_name, generic, field_name, field_ty);
+    let lens_impl = impl_lens_mut4index(
+        ty_name.clone(),
+        generic.clone(),
+        field_name.clone(),
+        field_ty.clone(),
+    );
+    let project_impl = impl_project4index(ty_name, generic, field_name, field_ty);
 
     quote! {
         #rf
         #traversal_impl
         #prism_impl
         #lens_impl
+        #project_impl
     }
 }
 
@@ -301,13 +336,20 @@ pub fn impl_mv4index(
         field_name.clone(),
         field_ty.clone(),
     );
-    let lens_impl = impl_lens4index(ty_name, generic, field_name, field_ty);
+    let lens_impl = impl_lens4index(
+        ty_name.clone(),
+        generic.clone(),
+        field_name.clone(),
+        field_ty.clone(),
+    );
+    let owned_impl = impl_owned4index(ty_name, generic, field_name, field_ty);
 
     quote! {
         #mt
         #traversal_impl
         #prism_impl
         #lens_impl
+        #owned_impl
     }
 }
 
@@ -550,6 +592,7 @@ fn impl_traversal_ref4variant(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalRef", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -571,18 +614,57 @@ fn impl_traversal_ref4variant(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = var_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Variant(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#var_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_ref(&self, optics: lens_rs::optics::#var_name<#traversal_param>) -> Vec<&#image_param> {
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_ref(x, optics.0, f),
+                     _ => std::ops::ControlFlow::Continue(()),
+                }
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#var_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#var_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
                 use #ty_name::*;
                 match self {
-                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_ref(x, optics.0),
-                     _ => vec![],
+                    #var_name(x) => {
+                        path.push(#segment);
+                        let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_ref(x, optics.0, path, f);
+                        path.pop();
+                        flow
+                    }
+                     _ => std::ops::ControlFlow::Continue(()),
                 }
             }
         }
@@ -597,6 +679,7 @@ fn impl_prism_ref4variant(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("PrismRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("TryPrismRef", Span::call_site());
     let prism_param = syn::Ident::new("__Pm", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -618,7 +701,20 @@ fn impl_prism_ref4variant(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#prism_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = var_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Variant(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#var_name<#prism_param>, #image_param> for #ty
@@ -633,6 +729,24 @@ fn impl_prism_ref4variant(
                 }
             }
         }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#var_name<#prism_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn miss_ref(&self, optics: lens_rs::optics::#var_name<#prism_param>, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => {
+                        path.push(#segment);
+                        let miss = <#field_ty as lens_rs::#indexed_trait<#prism_param, #image_param>>::miss_ref(x, optics.0, path);
+                        path.pop();
+                        miss
+                    }
+                     _ => Option::Some(lens_rs::path::PathMiss::new(path, lens_rs::path::Miss::Variant { expected: #segment_name })),
+                }
+            }
+        }
     }
 }
 
@@ -644,6 +758,7 @@ fn impl_traversal_mut4variant(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalMut", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalMut", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -665,18 +780,57 @@ fn impl_traversal_mut4variant(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = var_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Variant(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#var_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_mut(&mut self, optics: lens_rs::optics::#var_name<#traversal_param>) -> Vec<&mut #image_param> {
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_mut(x, optics.0, f),
+                     _ => std::ops::ControlFlow::Continue(()),
+                }
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#var_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#var_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
                 use #ty_name::*;
                 match self {
-                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_mut(x, optics.0),
-                     _ => vec![],
+                    #var_name(x) => {
+                        path.push(#segment);
+                        let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_mut(x, optics.0, path, f);
+                        path.pop();
+                        flow
+                    }
+                     _ => std::ops::ControlFlow::Continue(()),
                 }
             }
         }
@@ -730,6 +884,44 @@ fn impl_prism_mut4variant(
     }
 }
 
+fn impl_project4variant(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    var_name: syn::Ident,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let optics_trait = syn::Ident::new("ProjectMut", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+
+    // <...>
+    let params = Params::new(generic.clone(), vec![prism_param.clone()]);
+
+    // ty<...>
+    let ty = Type::new(ty_name.clone(), generic.clone());
+
+    // where ...
+    let optics_bounds = vec![parse_quote! { #field_ty: lens_rs::#optics_trait<#prism_param> }];
+    let constraints = Constraints::new(generic, optics_bounds);
+
+    quote! {
+        unsafe impl #params lens_rs::#optics_trait<lens_rs::optics::#var_name<#prism_param>> for #ty
+        where
+            #constraints
+        {
+            type Image = <#field_ty as lens_rs::#optics_trait<#prism_param>>::Image;
+
+            #[inline] unsafe fn project_mut(this: *mut Self, optics: lens_rs::optics::#var_name<#prism_param>) -> Option<*mut Self::Image> {
+                use #ty_name::*;
+                match *this {
+                    #var_name(ref mut x) => <#field_ty as lens_rs::#optics_trait<#prism_param>>::project_mut(x, optics.0),
+                     _ => Option::None,
+                }
+            }
+        }
+    }
+}
+
 fn impl_traversal4variant(
     ty_name: syn::Ident,
     generic: syn::Generics,
@@ -760,14 +952,27 @@ fn impl_traversal4variant(
         where
             #constraints
         {
-            #[inline] fn traverse(self, optics: lens_rs::optics::#var_name<#traversal_param>) -> Vec<#image_param>
+            #[inline] fn try_for_each_move<__B, __F>(self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_move(x, optics.0, f),
+                     _ => std::ops::ControlFlow::Continue(()),
+                }
+            }
+
+            #[inline] fn over<__F>(self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> Self
             where
+                __F: FnMut(#image_param) -> #image_param,
                 Self: Sized,
             {
                 use #ty_name::*;
                 match self {
-                    #var_name(x) => <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse(x, optics.0),
-                     _ => vec![],
+                    #var_name(x) => #var_name(<#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::over(x, optics.0, f)),
+                     other => other,
                 }
             }
         }
@@ -818,6 +1023,89 @@ fn impl_prism4variant(
     }
 }
 
+// the owned foci of the variant go on by `TraversalOwned` and `PrismOwned`
+fn impl_owned4variant(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    var_name: syn::Ident,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+
+    // <...>
+    let traversal_params = Params::new(
+        generic.clone(),
+        vec![traversal_param.clone(), image_param.clone()],
+    );
+    let prism_params = Params::new(
+        generic.clone(),
+        vec![prism_param.clone(), image_param.clone()],
+    );
+
+    // ty<...>
+    let ty = Type::new(ty_name.clone(), generic.clone());
+
+    // where ...
+    let traversal_constraints = Constraints::new(
+        generic.clone(),
+        vec![parse_quote! { #field_ty: lens_rs::TraversalOwned<#traversal_param, #image_param> }],
+    );
+    let prism_constraints = Constraints::new(
+        generic,
+        vec![parse_quote! { #field_ty: lens_rs::PrismOwned<#prism_param, #image_param> }],
+    );
+
+    quote! {
+        impl #traversal_params lens_rs::TraversalOwned<lens_rs::optics::#var_name<#traversal_param>, #image_param> for #ty
+        where
+            #traversal_constraints
+        {
+            #[inline] fn try_for_each_owned<__B, __F>(self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::try_for_each_owned(x, optics.0, f),
+                     _ => std::ops::ControlFlow::Continue(()),
+                }
+            }
+
+            #[inline] fn over_owned<__F>(self, optics: lens_rs::optics::#var_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => #var_name(<#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::over_owned(x, optics.0, f)),
+                     other => other,
+                }
+            }
+        }
+
+        impl #prism_params lens_rs::PrismOwned<lens_rs::optics::#var_name<#prism_param>, #image_param> for #ty
+        where
+            #prism_constraints
+        {
+            #[inline] fn preview_owned(self, optics: lens_rs::optics::#var_name<#prism_param>) -> Option<#image_param>
+            where
+                Self: Sized,
+            {
+                use #ty_name::*;
+                match self {
+                    #var_name(x) => <#field_ty as lens_rs::PrismOwned<#prism_param, #image_param>>::preview_owned(x, optics.0),
+                     _ => Option::None,
+                }
+            }
+        }
+    }
+}
+
 fn impl_traversal_ref4field(
     ty_name: syn::Ident,
     generic: syn::Generics,
@@ -826,6 +1114,7 @@ fn impl_traversal_ref4field(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalRef", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -847,15 +1136,48 @@ fn impl_traversal_ref4field(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = field_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_ref(&self, optics: lens_rs::optics::#field_name<#traversal_param>) -> Vec<&#image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_ref(&self.#field_name, optics.0)
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#field_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_ref(&self.#field_name, optics.0, f)
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#field_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_ref(&self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -869,6 +1191,7 @@ fn impl_prism_ref4field(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("PrismRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("TryPrismRef", Span::call_site());
     let prism_param = syn::Ident::new("__Pm", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -890,7 +1213,20 @@ fn impl_prism_ref4field(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#prism_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = field_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#prism_param>, #image_param> for #ty
@@ -901,6 +1237,18 @@ fn impl_prism_ref4field(
                 <#field_ty as lens_rs::#optics_trait<#prism_param, #image_param>>::preview_ref(&self.#field_name, optics.0)
             }
         }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#field_name<#prism_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn miss_ref(&self, optics: lens_rs::optics::#field_name<#prism_param>, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                path.push(#segment);
+                let miss = <#field_ty as lens_rs::#indexed_trait<#prism_param, #image_param>>::miss_ref(&self.#field_name, optics.0, path);
+                path.pop();
+                miss
+            }
+        }
     }
 }
 
@@ -955,6 +1303,7 @@ fn impl_traversal_mut4field(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalMut", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalMut", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
@@ -976,15 +1325,48 @@ fn impl_traversal_mut4field(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = field_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_mut(&mut self, optics: lens_rs::optics::#field_name<#traversal_param>) -> Vec<&mut #image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_mut(&mut self.#field_name, optics.0)
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#field_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_mut(&mut self.#field_name, optics.0, f)
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#field_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_mut(&mut self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -1076,61 +1458,106 @@ fn impl_lens_mut4field(
     }
 }
 
-fn impl_traversal4field(
+fn impl_project4field(
     ty_name: syn::Ident,
     generic: syn::Generics,
 
     field_name: syn::Ident,
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
-    let optics_trait = syn::Ident::new("Traversal", Span::call_site());
-    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
-    let image_param = syn::Ident::new("__Image", Span::call_site());
+    let optics_trait = syn::Ident::new("ProjectMut", Span::call_site());
+    let lens_param = syn::Ident::new("__Ls", Span::call_site());
 
     // <...>
-    let params = Params::new(
-        generic.clone(),
-        vec![traversal_param.clone(), image_param.clone()],
-    );
+    let params = Params::new(generic.clone(), vec![lens_param.clone()]);
 
     // ty<...>
     let ty = Type::new(ty_name, generic.clone());
 
     // where ...
-    let optics_bounds =
-        vec![parse_quote! { #field_ty: lens_rs::#optics_trait<#traversal_param, #image_param> }];
+    let optics_bounds = vec![parse_quote! { #field_ty: lens_rs::#optics_trait<#lens_param> }];
     let constraints = Constraints::new(generic, optics_bounds);
 
     quote! {
-        impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
+        unsafe impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#lens_param>> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse(self, optics: lens_rs::optics::#field_name<#traversal_param>) -> Vec<#image_param>
-            where
-                Self: Sized,
-            {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse(self.#field_name, optics.0)
+            type Image = <#field_ty as lens_rs::#optics_trait<#lens_param>>::Image;
+
+            #[inline] unsafe fn project_mut(this: *mut Self, optics: lens_rs::optics::#field_name<#lens_param>) -> Option<*mut Self::Image> {
+                <#field_ty as lens_rs::#optics_trait<#lens_param>>::project_mut(::core::ptr::addr_of_mut!((*this).#field_name), optics.0)
             }
         }
     }
 }
 
-fn impl_prism4field(
+fn impl_traversal4field(
     ty_name: syn::Ident,
     generic: syn::Generics,
 
     field_name: syn::Ident,
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
-    let optics_trait = syn::Ident::new("Prism", Span::call_site());
-    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let optics_trait = syn::Ident::new("Traversal", Span::call_site());
+    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
 
     // <...>
     let params = Params::new(
         generic.clone(),
-        vec![prism_param.clone(), image_param.clone()],
+        vec![traversal_param.clone(), image_param.clone()],
+    );
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let optics_bounds =
+        vec![parse_quote! { #field_ty: lens_rs::#optics_trait<#traversal_param, #image_param> }];
+    let constraints = Constraints::new(generic, optics_bounds);
+
+    quote! {
+        impl #params lens_rs::#optics_trait<lens_rs::optics::#field_name<#traversal_param>, #image_param> for #ty
+        where
+            #constraints
+        {
+            #[inline] fn try_for_each_move<__B, __F>(self, optics: lens_rs::optics::#field_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_move(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over<__F>(self, optics: lens_rs::optics::#field_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::over(this.#field_name, optics.0, f);
+                this
+            }
+        }
+    }
+}
+
+fn impl_prism4field(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    field_name: syn::Ident,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let optics_trait = syn::Ident::new("Prism", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+
+    // <...>
+    let params = Params::new(
+        generic.clone(),
+        vec![prism_param.clone(), image_param.clone()],
     );
 
     // ty<...>
@@ -1196,6 +1623,80 @@ fn impl_lens4field(
     }
 }
 
+// the owned foci of the field go on by `TraversalOwned` and `PrismOwned`
+fn impl_owned4field(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    field_name: syn::Ident,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+    let optics_name = &field_name;
+
+    // <...>
+    let traversal_params = Params::new(
+        generic.clone(),
+        vec![traversal_param.clone(), image_param.clone()],
+    );
+    let prism_params = Params::new(
+        generic.clone(),
+        vec![prism_param.clone(), image_param.clone()],
+    );
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let traversal_constraints = Constraints::new(
+        generic.clone(),
+        vec![parse_quote! { #field_ty: lens_rs::TraversalOwned<#traversal_param, #image_param> }],
+    );
+    let prism_constraints = Constraints::new(
+        generic,
+        vec![parse_quote! { #field_ty: lens_rs::PrismOwned<#prism_param, #image_param> }],
+    );
+
+    quote! {
+        impl #traversal_params lens_rs::TraversalOwned<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
+        where
+            #traversal_constraints
+        {
+            #[inline] fn try_for_each_owned<__B, __F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::try_for_each_owned(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over_owned<__F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::over_owned(this.#field_name, optics.0, f);
+                this
+            }
+        }
+
+        impl #prism_params lens_rs::PrismOwned<lens_rs::optics::#optics_name<#prism_param>, #image_param> for #ty
+        where
+            #prism_constraints
+        {
+            #[inline] fn preview_owned(self, optics: lens_rs::optics::#optics_name<#prism_param>) -> Option<#image_param>
+            where
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::PrismOwned<#prism_param, #image_param>>::preview_owned(self.#field_name, optics.0)
+            }
+        }
+    }
+}
+
 fn impl_traversal_ref4index(
     ty_name: syn::Ident,
     generic: syn::Generics,
@@ -1204,6 +1705,7 @@ fn impl_traversal_ref4index(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalRef", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let optics_name = format_ident!("_{}", field_name);
@@ -1226,15 +1728,48 @@ fn impl_traversal_ref4index(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_ref(&self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<&#image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_ref(&self.#field_name, optics.0)
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_ref(&self.#field_name, optics.0, f)
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#optics_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_ref(&self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -1248,6 +1783,7 @@ fn impl_prism_ref4index(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("PrismRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("TryPrismRef", Span::call_site());
     let prism_param = syn::Ident::new("__Pm", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let optics_name = format_ident!("_{}", field_name);
@@ -1270,7 +1806,20 @@ fn impl_prism_ref4index(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#prism_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#optics_name<#prism_param>, #image_param> for #ty
@@ -1281,6 +1830,18 @@ fn impl_prism_ref4index(
                 <#field_ty as lens_rs::#optics_trait<#prism_param, #image_param>>::preview_ref(&self.#field_name, optics.0)
             }
         }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#prism_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn miss_ref(&self, optics: lens_rs::optics::#optics_name<#prism_param>, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                path.push(#segment);
+                let miss = <#field_ty as lens_rs::#indexed_trait<#prism_param, #image_param>>::miss_ref(&self.#field_name, optics.0, path);
+                path.pop();
+                miss
+            }
+        }
     }
 }
 
@@ -1336,6 +1897,7 @@ fn impl_traversal_mut4index(
     field_ty: syn::Type,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalMut", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalMut", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let optics_name = format_ident!("_{}", field_name);
@@ -1358,15 +1920,48 @@ fn impl_traversal_mut4index(
             #image_param: ?Sized
         },
     ];
-    let constraints = Constraints::new(generic, optics_bounds);
+    let indexed_bounds = vec![
+        parse_quote! {
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        },
+        parse_quote! {
+            #image_param: ?Sized
+        },
+    ];
+    let constraints = Constraints::new(generic.clone(), optics_bounds);
+    let indexed_constraints = Constraints::new(generic, indexed_bounds);
+
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
 
     quote! {
         impl #params lens_rs::#optics_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
         where
             #constraints
         {
-            #[inline] fn traverse_mut(&mut self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<&mut #image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_mut(&mut self.#field_name, optics.0)
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_mut(&mut self.#field_name, optics.0, f)
+            }
+        }
+
+        impl #params lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
+        where
+            #indexed_constraints
+        {
+            #[inline] fn try_ifor_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#optics_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_mut(&mut self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -1460,6 +2055,41 @@ fn impl_lens_mut4index(
     }
 }
 
+fn impl_project4index(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    field_name: syn::Index,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let optics_trait = syn::Ident::new("ProjectMut", Span::call_site());
+    let lens_param = syn::Ident::new("__Ls", Span::call_site());
+    let optics_name = format_ident!("_{}", field_name);
+
+    // <...>
+    let params = Params::new(generic.clone(), vec![lens_param.clone()]);
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let optics_bounds = vec![parse_quote! { #field_ty: lens_rs::#optics_trait<#lens_param> }];
+    let constraints = Constraints::new(generic, optics_bounds);
+
+    quote! {
+        unsafe impl #params lens_rs::#optics_trait<lens_rs::optics::#optics_name<#lens_param>> for #ty
+        where
+            #constraints
+        {
+            type Image = <#field_ty as lens_rs::#optics_trait<#lens_param>>::Image;
+
+            #[inline] unsafe fn project_mut(this: *mut Self, optics: lens_rs::optics::#optics_name<#lens_param>) -> Option<*mut Self::Image> {
+                <#field_ty as lens_rs::#optics_trait<#lens_param>>::project_mut(::core::ptr::addr_of_mut!((*this).#field_name), optics.0)
+            }
+        }
+    }
+}
+
 fn impl_traversal4index(
     ty_name: syn::Ident,
     generic: syn::Generics,
@@ -1491,11 +2121,22 @@ fn impl_traversal4index(
         where
             #constraints
         {
-            #[inline] fn traverse(self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<#image_param>
+            #[inline] fn try_for_each_move<__B, __F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
             where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
                 Self: Sized,
             {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse(self.#field_name, optics.0)
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_move(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over<__F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::over(this.#field_name, optics.0, f);
+                this
             }
         }
     }
@@ -1583,6 +2224,80 @@ fn impl_lens4index(
     }
 }
 
+// the same as `impl_owned4field`
+fn impl_owned4index(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    field_name: syn::Index,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+    let optics_name = format_ident!("_{}", field_name);
+
+    // <...>
+    let traversal_params = Params::new(
+        generic.clone(),
+        vec![traversal_param.clone(), image_param.clone()],
+    );
+    let prism_params = Params::new(
+        generic.clone(),
+        vec![prism_param.clone(), image_param.clone()],
+    );
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let traversal_constraints = Constraints::new(
+        generic.clone(),
+        vec![parse_quote! { #field_ty: lens_rs::TraversalOwned<#traversal_param, #image_param> }],
+    );
+    let prism_constraints = Constraints::new(
+        generic,
+        vec![parse_quote! { #field_ty: lens_rs::PrismOwned<#prism_param, #image_param> }],
+    );
+
+    quote! {
+        impl #traversal_params lens_rs::TraversalOwned<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for #ty
+        where
+            #traversal_constraints
+        {
+            #[inline] fn try_for_each_owned<__B, __F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::try_for_each_owned(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over_owned<__F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::over_owned(this.#field_name, optics.0, f);
+                this
+            }
+        }
+
+        impl #prism_params lens_rs::PrismOwned<lens_rs::optics::#optics_name<#prism_param>, #image_param> for #ty
+        where
+            #prism_constraints
+        {
+            #[inline] fn preview_owned(self, optics: lens_rs::optics::#optics_name<#prism_param>) -> Option<#image_param>
+            where
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::PrismOwned<#prism_param, #image_param>>::preview_owned(self.#field_name, optics.0)
+            }
+        }
+    }
+}
+
 pub fn impl_empty(
     ty_name: syn::Ident,
     generic: syn::Generics,
@@ -1608,8 +2323,12 @@ pub fn impl_empty(
             #constraints
 
         {
-            #[inline] fn traverse_ref(&self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<& #image_param> {
-                vec![]
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                std::ops::ControlFlow::Continue(())
             }
         }
 
@@ -1619,8 +2338,12 @@ pub fn impl_empty(
             #constraints
 
         {
-            #[inline] fn traverse_mut(&mut self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<&mut #image_param> {
-                vec![]
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                std::ops::ControlFlow::Continue(())
             }
         }
 
@@ -1628,11 +2351,20 @@ pub fn impl_empty(
         where
             #constraints
         {
-            #[inline] fn traverse(self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<#image_param>
+            #[inline] fn try_for_each_move<__B, __F>(self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                std::ops::ControlFlow::Continue(())
+            }
+
+            #[inline] fn over<__F>(self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> Self
             where
+                __F: FnMut(#image_param) -> #image_param,
                 Self: Sized,
             {
-                vec![]
+                self
             }
         }
 
@@ -1646,6 +2378,16 @@ pub fn impl_empty(
             }
         }
 
+        impl #params lens_rs::TryPrismRef<lens_rs::optics::#field_name<#lens_param >, #image_param> for #ty
+        where
+            #image_param: ?Sized,
+            #constraints
+        {
+            #[inline] fn miss_ref(&self, _optics: lens_rs::optics::#field_name<#lens_param >, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                Option::Some(lens_rs::path::PathMiss::new(path, lens_rs::path::Miss::NoFocus { optic: stringify!(#field_name) }))
+            }
+        }
+
         impl #params lens_rs::PrismMut<lens_rs::optics::#field_name<#lens_param >, #image_param> for #ty
         where
             #image_param: ?Sized,
@@ -1795,7 +2537,11 @@ pub fn impl4tuple(
 
     let traversal = impl_traversal4tuple(tuple.clone(), field_name.clone(), field_ty.clone());
     let prism = impl_prism4tuple(tuple.clone(), field_name.clone(), field_ty.clone());
-    let lens = impl_lens4tuple(tuple, field_name, field_ty);
+    let lens = impl_lens4tuple(tuple.clone(), field_name.clone(), field_ty.clone());
+
+    let owned = impl_owned4tuple(tuple.clone(), field_name.clone(), field_ty.clone());
+
+    let project = impl_project4tuple(tuple, field_name, field_ty);
 
     quote! {
         #traversal_ref
@@ -1810,6 +2556,9 @@ pub fn impl4tuple(
         #lens_mut
         #lens
 
+        #owned
+
+        #project
     }
 }
 
@@ -1820,19 +2569,45 @@ fn impl_traversal_ref4tuple(
     field_ty: syn::Ident,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalRef", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let params = &tuple.elems;
     let optics_name = format_ident!("_{}", field_name);
 
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
+
     quote! {
         impl<#image_param, #traversal_param, #params> lens_rs::#optics_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for (#params)
         where
             #image_param: ?Sized,
             #field_ty: lens_rs::#optics_trait<#traversal_param, #image_param>
         {
-            #[inline] fn traverse_ref(&self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<&#image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_ref(&self.#field_name, optics.0)
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_ref(&self.#field_name, optics.0, f)
+            }
+        }
+
+        impl<#image_param, #traversal_param, #params> lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for (#params)
+        where
+            #image_param: ?Sized,
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        {
+            #[inline] fn try_ifor_each_ref<'__a, __B, __F>(&'__a self, optics: lens_rs::optics::#optics_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_ref(&self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -1845,11 +2620,16 @@ fn impl_prism_ref4tuple(
     field_ty: syn::Ident,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("PrismRef", Span::call_site());
+    let indexed_trait = syn::Ident::new("TryPrismRef", Span::call_site());
     let prism_param = syn::Ident::new("__Pm", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let params = &tuple.elems;
     let optics_name = format_ident!("_{}", field_name);
 
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
+
     quote! {
         impl<#image_param, #prism_param, #params> lens_rs::#optics_trait<lens_rs::optics::#optics_name<#prism_param>, #image_param> for (#params)
         where
@@ -1860,6 +2640,19 @@ fn impl_prism_ref4tuple(
                 <#field_ty as lens_rs::#optics_trait<#prism_param, #image_param>>::preview_ref(&self.#field_name, optics.0)
             }
         }
+
+        impl<#image_param, #prism_param, #params> lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#prism_param>, #image_param> for (#params)
+        where
+            #image_param: ?Sized,
+            #field_ty: lens_rs::#indexed_trait<#prism_param, #image_param>
+        {
+            #[inline] fn miss_ref(&self, optics: lens_rs::optics::#optics_name<#prism_param>, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                path.push(#segment);
+                let miss = <#field_ty as lens_rs::#indexed_trait<#prism_param, #image_param>>::miss_ref(&self.#field_name, optics.0, path);
+                path.pop();
+                miss
+            }
+        }
     }
 }
 
@@ -1895,19 +2688,45 @@ fn impl_traversal_mut4tuple(
     field_ty: syn::Ident,
 ) -> proc_macro2::TokenStream {
     let optics_trait = syn::Ident::new("TraversalMut", Span::call_site());
+    let indexed_trait = syn::Ident::new("IndexedTraversalMut", Span::call_site());
     let traversal_param = syn::Ident::new("__Tr", Span::call_site());
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let params = &tuple.elems;
     let optics_name = format_ident!("_{}", field_name);
 
+    // the segment of the path
+    let segment_name = optics_name.to_string();
+    let segment = quote! { lens_rs::path::Segment::Field(#segment_name) };
+
     quote! {
         impl<#image_param, #traversal_param, #params> lens_rs::#optics_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for (#params)
         where
             #image_param: ?Sized,
             #field_ty: lens_rs::#optics_trait<#traversal_param, #image_param>
         {
-            #[inline] fn traverse_mut(&mut self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<&mut #image_param> {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse_mut(&mut self.#field_name, optics.0)
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_mut(&mut self.#field_name, optics.0, f)
+            }
+        }
+
+        impl<#image_param, #traversal_param, #params> lens_rs::#indexed_trait<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for (#params)
+        where
+            #image_param: ?Sized,
+            #field_ty: lens_rs::#indexed_trait<#traversal_param, #image_param>
+        {
+            #[inline] fn try_ifor_each_mut<'__a, __B, __F>(&'__a mut self, optics: lens_rs::optics::#optics_name<#traversal_param>, path: &mut lens_rs::path::OpticPath, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&lens_rs::path::OpticPath, &'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                path.push(#segment);
+                let flow = <#field_ty as lens_rs::#indexed_trait<#traversal_param, #image_param>>::try_ifor_each_mut(&mut self.#field_name, optics.0, path, f);
+                path.pop();
+                flow
             }
         }
     }
@@ -1963,6 +2782,31 @@ fn impl_lens_mut4tuple(
     }
 }
 
+fn impl_project4tuple(
+    tuple: crate::Tuple,
+
+    field_name: syn::Index,
+    field_ty: syn::Ident,
+) -> proc_macro2::TokenStream {
+    let optics_trait = syn::Ident::new("ProjectMut", Span::call_site());
+    let lens_param = syn::Ident::new("__Ls", Span::call_site());
+    let params = &tuple.elems;
+    let optics_name = format_ident!("_{}", field_name);
+
+    quote! {
+        unsafe impl<#lens_param, #params> lens_rs::#optics_trait<lens_rs::optics::#optics_name<#lens_param>> for (#params)
+        where
+            #field_ty: lens_rs::#optics_trait<#lens_param>
+        {
+            type Image = <#field_ty as lens_rs::#optics_trait<#lens_param>>::Image;
+
+            #[inline] unsafe fn project_mut(this: *mut Self, optics: lens_rs::optics::#optics_name<#lens_param>) -> Option<*mut Self::Image> {
+                <#field_ty as lens_rs::#optics_trait<#lens_param>>::project_mut(::core::ptr::addr_of_mut!((*this).#field_name), optics.0)
+            }
+        }
+    }
+}
+
 fn impl_traversal4tuple(
     tuple: crate::Tuple,
 
@@ -1980,11 +2824,22 @@ fn impl_traversal4tuple(
         where
             #field_ty: lens_rs::#optics_trait<#traversal_param, #image_param>
         {
-            #[inline] fn traverse(self, optics: lens_rs::optics::#optics_name<#traversal_param>) -> Vec<#image_param>
+            #[inline] fn try_for_each_move<__B, __F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::try_for_each_move(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over<__F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> Self
             where
+                __F: FnMut(#image_param) -> #image_param,
                 Self: Sized,
             {
-                <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::traverse(self.#field_name, optics.0)
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::#optics_trait<#traversal_param, #image_param>>::over(this.#field_name, optics.0, f);
+                this
             }
         }
     }
@@ -2044,6 +2899,56 @@ fn impl_lens4tuple(
     }
 }
 
+fn impl_owned4tuple(
+    tuple: crate::Tuple,
+
+    field_name: syn::Index,
+    field_ty: syn::Ident,
+) -> proc_macro2::TokenStream {
+    let traversal_param = syn::Ident::new("__Tr", Span::call_site());
+    let prism_param = syn::Ident::new("__Pm", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+    let params = &tuple.elems;
+    let optics_name = format_ident!("_{}", field_name);
+
+    quote! {
+        impl<#image_param, #traversal_param, #params> lens_rs::TraversalOwned<lens_rs::optics::#optics_name<#traversal_param>, #image_param> for (#params)
+        where
+            #field_ty: lens_rs::TraversalOwned<#traversal_param, #image_param>
+        {
+            #[inline] fn try_for_each_owned<__B, __F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::try_for_each_owned(self.#field_name, optics.0, f)
+            }
+
+            #[inline] fn over_owned<__F>(self, optics: lens_rs::optics::#optics_name<#traversal_param>, f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                let mut this = self;
+                this.#field_name = <#field_ty as lens_rs::TraversalOwned<#traversal_param, #image_param>>::over_owned(this.#field_name, optics.0, f);
+                this
+            }
+        }
+
+        impl<#image_param, #prism_param, #params> lens_rs::PrismOwned<lens_rs::optics::#optics_name<#prism_param>, #image_param> for (#params)
+        where
+            #field_ty: lens_rs::PrismOwned<#prism_param, #image_param>
+        {
+            #[inline] fn preview_owned(self, optics: lens_rs::optics::#optics_name<#prism_param>) -> Option<#image_param>
+            where
+                Self: Sized,
+            {
+                <#field_ty as lens_rs::PrismOwned<#prism_param, #image_param>>::preview_owned(self.#field_name, optics.0)
+            }
+        }
+    }
+}
+
 pub fn impl_empty4tuple(tuple: crate::Tuple, field_name: syn::Ident) -> proc_macro2::TokenStream {
     let image_param = syn::Ident::new("__Image", Span::call_site());
     let lens_param = syn::Ident::new("__Opt", Span::call_site());
@@ -2055,8 +2960,12 @@ pub fn impl_empty4tuple(tuple: crate::Tuple, field_name: syn::Ident) -> proc_mac
             #image_param: ?Sized,
 
         {
-            #[inline] fn traverse_ref(&self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<& #image_param> {
-                vec![]
+            #[inline] fn try_for_each_ref<'__a, __B, __F>(&'__a self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                std::ops::ControlFlow::Continue(())
             }
         }
 
@@ -2065,18 +2974,31 @@ pub fn impl_empty4tuple(tuple: crate::Tuple, field_name: syn::Ident) -> proc_mac
             #image_param: ?Sized,
 
         {
-            #[inline] fn traverse_mut(&mut self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<&mut #image_param> {
-                vec![]
+            #[inline] fn try_for_each_mut<'__a, __B, __F>(&'__a mut self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
+            where
+                __F: FnMut(&'__a mut #image_param) -> std::ops::ControlFlow<__B>,
+                #image_param: '__a,
+            {
+                std::ops::ControlFlow::Continue(())
             }
         }
 
         impl<#image_param, #lens_param, #params> lens_rs::Traversal<lens_rs::optics::#field_name<#lens_param >, #image_param> for (#params)
         {
-            #[inline] fn traverse(self, _optics: lens_rs::optics::#field_name<#lens_param >) -> Vec<#image_param>
+            #[inline] fn try_for_each_move<__B, __F>(self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> std::ops::ControlFlow<__B>
             where
+                __F: FnMut(#image_param) -> std::ops::ControlFlow<__B>,
                 Self: Sized,
             {
-                vec![]
+                std::ops::ControlFlow::Continue(())
+            }
+
+            #[inline] fn over<__F>(self, _optics: lens_rs::optics::#field_name<#lens_param >, _f: __F) -> Self
+            where
+                __F: FnMut(#image_param) -> #image_param,
+                Self: Sized,
+            {
+                self
             }
         }
 
@@ -2089,6 +3011,15 @@ pub fn impl_empty4tuple(tuple: crate::Tuple, field_name: syn::Ident) -> proc_mac
             }
         }
 
+        impl<#image_param, #lens_param, #params> lens_rs::TryPrismRef<lens_rs::optics::#field_name<#lens_param >, #image_param> for (#params)
+        where
+            #image_param: ?Sized,
+        {
+            #[inline] fn miss_ref(&self, _optics: lens_rs::optics::#field_name<#lens_param >, path: &mut lens_rs::path::OpticPath) -> Option<lens_rs::path::PathMiss> {
+                Option::Some(lens_rs::path::PathMiss::new(path, lens_rs::path::Miss::NoFocus { optic: stringify!(#field_name) }))
+            }
+        }
+
         impl<#image_param, #lens_param, #params> lens_rs::PrismMut<lens_rs::optics::#field_name<#lens_param >, #image_param> for (#params)
         where
             #image_param: ?Sized,
@@ -2109,3 +3040,211 @@ pub fn impl_empty4tuple(tuple: crate::Tuple, field_name: syn::Ident) -> proc_mac
         }
     }
 }
+
+pub fn impl_newtype(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    member: syn::Member,
+    field_ty: syn::Type,
+) -> proc_macro2::TokenStream {
+    // <...>
+    let params = Params::new(generic.clone(), vec![]);
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let constraints = Constraints::new(generic, vec![]);
+
+    quote! {
+        impl #params lens_rs::Newtype for #ty
+        where
+            #constraints
+        {
+            type Inner = #field_ty;
+
+            #[inline] fn inner_ref(&self) -> &#field_ty {
+                &self.#member
+            }
+
+            #[inline] fn inner_mut(&mut self) -> &mut #field_ty {
+                &mut self.#member
+            }
+
+            #[inline] fn into_inner(self) -> #field_ty {
+                self.#member
+            }
+
+            #[inline] fn from_inner(inner: #field_ty) -> Self {
+                Self { #member: inner }
+            }
+        }
+    }
+}
+
+pub fn impl_iso4tuple_repr(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    members: Vec<syn::Member>,
+    field_tys: Vec<syn::Type>,
+) -> proc_macro2::TokenStream {
+    let iso_param = syn::Ident::new("__Is", Span::call_site());
+    let image_param = syn::Ident::new("__Image", Span::call_site());
+    let bindings = (0..members.len())
+        .map(|i| format_ident!("__{}", i))
+        .collect::<Vec<_>>();
+
+    // (field types...)
+    let tuple_ty: syn::Type = parse_quote! { (#(#field_tys,)*) };
+
+    // <...>
+    let params = Params::new(
+        generic.clone(),
+        vec![iso_param.clone(), image_param.clone()],
+    );
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let review_constraints = Constraints::new(
+        generic.clone(),
+        vec![parse_quote! { #tuple_ty: lens_rs::Review<#iso_param, #image_param> }],
+    );
+    let iso_constraints = Constraints::new(
+        generic,
+        vec![parse_quote! { #tuple_ty: lens_rs::Iso<#iso_param, #image_param> }],
+    );
+
+    quote! {
+        impl #params lens_rs::Review<lens_rs::optics::_tuple<#iso_param>, #image_param> for #ty
+        where
+            #review_constraints
+        {
+            fn review(optics: lens_rs::optics::_tuple<#iso_param>, from: #image_param) -> Self {
+                let (#(#bindings,)*) = <#tuple_ty as lens_rs::Review<#iso_param, #image_param>>::review(optics.0, from);
+                Self { #(#members: #bindings),* }
+            }
+        }
+
+        impl #params lens_rs::Iso<lens_rs::optics::_tuple<#iso_param>, #image_param> for #ty
+        where
+            #iso_constraints
+        {
+            #[inline] fn to(self, optics: lens_rs::optics::_tuple<#iso_param>) -> #image_param {
+                <#tuple_ty as lens_rs::Iso<#iso_param, #image_param>>::to((#(self.#members,)*), optics.0)
+            }
+        }
+    }
+}
+
+pub fn impl_reflect4struct(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    members: Vec<syn::Member>,
+    field_tys: Vec<syn::Type>,
+) -> proc_macro2::TokenStream {
+    let names = members
+        .iter()
+        .map(|member| match member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => format!("_{}", index.index),
+        })
+        .collect::<Vec<_>>();
+
+    // <...>
+    let params = Params::new(generic.clone(), vec![]);
+
+    // ty<...>
+    let ty = Type::new(ty_name, generic.clone());
+
+    // where ...
+    let constraints = Constraints::new(
+        generic,
+        std::iter::once(parse_quote! { #ty: std::fmt::Debug + 'static })
+            .chain(
+                field_tys
+                    .iter()
+                    .map(|field_ty| parse_quote! { #field_ty: lens_rs::path::Reflect }),
+            )
+            .collect(),
+    );
+
+    quote! {
+        impl #params lens_rs::path::Reflect for #ty
+        where
+            #constraints
+        {
+            fn field_ref(&self, name: &str) -> Option<&dyn lens_rs::path::Reflect> {
+                match name {
+                    #(#names => Some(&self.#members),)*
+                    _ => None,
+                }
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn as_debug(&self) -> &dyn std::fmt::Debug {
+                self
+            }
+        }
+    }
+}
+
+pub fn impl_reflect4enum(
+    ty_name: syn::Ident,
+    generic: syn::Generics,
+
+    var_names: Vec<syn::Ident>,
+    field_tys: Vec<syn::Type>,
+) -> proc_macro2::TokenStream {
+    let names = var_names
+        .iter()
+        .map(|var_name| var_name.to_string())
+        .collect::<Vec<_>>();
+
+    // <...>
+    let params = Params::new(generic.clone(), vec![]);
+
+    // ty<...>
+    let ty = Type::new(ty_name.clone(), generic.clone());
+
+    // where ...
+    let constraints = Constraints::new(
+        generic,
+        std::iter::once(parse_quote! { #ty: std::fmt::Debug + 'static })
+            .chain(
+                field_tys
+                    .iter()
+                    .map(|field_ty| parse_quote! { #field_ty: lens_rs::path::Reflect }),
+            )
+            .collect(),
+    );
+
+    quote! {
+        impl #params lens_rs::path::Reflect for #ty
+        where
+            #constraints
+        {
+            fn field_ref(&self, name: &str) -> Option<&dyn lens_rs::path::Reflect> {
+                match (name, self) {
+                    #((#names, #ty_name::#var_names(x)) => Some(x),)*
+                    _ => None,
+                }
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn as_debug(&self) -> &dyn std::fmt::Debug {
+                self
+            }
+        }
+    }
+}
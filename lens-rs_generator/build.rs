@@ -8,7 +8,8 @@ use syn::{ItemEnum, ItemStruct};
 
 fn main() {
     let mut optics_set = OpticsSet::new();
-    let mut optics_collector = OpticsCollector(&mut optics_set);
+    let mut variant_set = OpticsSet::new();
+    let mut optics_collector = OpticsCollector(&mut optics_set, &mut variant_set);
 
     for section in inwelling(Opts {
         watch_manifest: true,
@@ -26,22 +27,54 @@ fn main() {
     }
 
     let mut output = String::new();
-    for optic_name in optics_set {
+    for optic_name in &optics_set {
         if let "Some" | "None" | "Ok" | "Err" | "_0" | "_1" | "_2" | "_3" | "_4" | "_5" | "_6"
         | "_7" | "_8" | "_9" | "_10" | "_11" | "_12" | "_13" | "_14" | "_15" | "_16" =
-            &*optic_name
+            &**optic_name
         {
             continue;
         }
+        // a name of both a field and a variant is taken as a variant, which is stricter
+        let cons = if variant_set.contains(optic_name) {
+            "cons_variant"
+        } else {
+            "cons"
+        };
         output += &format!(
             r"
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[allow(non_camel_case_types)]
-pub struct {}<Optics>(pub Optics);
+pub struct {0}<Optics>(pub Optics);
+
+impl<Optics: crate::SplitAtLock> crate::SplitAtLock for {0}<Optics> {{
+    type Prefix = {0}<Optics::Prefix>;
+    type Rest = Optics::Rest;
+
+    #[inline]
+    fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {{
+        let (prefix, rest) = self.0.split_at_lock();
+        ({0}(prefix), rest)
+    }}
+}}
+
+impl<Optics: crate::StaticPath> crate::StaticPath for {0}<Optics> {{
+    const PATH: crate::Segments = crate::Segments::{1}(stringify!({0}), Optics::PATH);
+}}
+
+impl<Optics: crate::SplitStaticPath> crate::SplitStaticPath for {0}<Optics> {{
+    type Prefix = {0}<Optics::Prefix>;
+    type Rest = Optics::Rest;
+
+    #[inline]
+    fn split_static_path(self) -> (Self::Prefix, Self::Rest) {{
+        let (prefix, rest) = self.0.split_static_path();
+        ({0}(prefix), rest)
+    }}
+}}
 
         ",
-            optic_name
+            optic_name, cons
         );
     }
 
@@ -51,7 +84,7 @@ pub struct {}<Optics>(pub Optics);
 
 type OpticsSet = HashSet<String>;
 
-struct OpticsCollector<'a>(&'a mut OpticsSet);
+struct OpticsCollector<'a>(&'a mut OpticsSet, &'a mut OpticsSet);
 
 impl<'a> OpticsCollector<'a> {
     #[cfg(feature = "structx")]
@@ -86,6 +119,7 @@ impl<'a> Visit<'_> for OpticsCollector<'a> {
         for variant in &item_enum.variants {
             if variant_with_optic_attr(variant) {
                 self.0.insert(format!("{}", variant.ident));
+                self.1.insert(format!("{}", variant.ident));
             }
         }
     }
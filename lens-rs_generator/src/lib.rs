@@ -2,6 +2,197 @@ use proc_macro2::Span;
 use syn::visit::Visit;
 use syn::ItemStruct;
 
+/// to split the optics at the first lock optic (`_borrow`, `_lock`, `_read` or `_write`)
+/// into the optics reaching the lock and the optics going on behind the lock
+pub trait SplitAtLock {
+    type Prefix;
+    type Rest;
+
+    fn split_at_lock(self) -> (Self::Prefix, Self::Rest);
+}
+
+/// the rest of the optics without any lock optic
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NoLock;
+
+macro_rules! impl_split_at_lock {
+    ($($optic:ident<$($param:ident,)*>($($field:tt)*)),* $(,)?) => {
+        $(
+            impl<$($param,)* Optic> crate::SplitAtLock for $optic<$($param,)* Optic>
+            where
+                Optic: crate::SplitAtLock,
+            {
+                type Prefix = $optic<$($param,)* Optic::Prefix>;
+                type Rest = Optic::Rest;
+
+                #[inline]
+                fn split_at_lock(self) -> (Self::Prefix, Self::Rest) {
+                    let $optic(optic, $($field)*) = self;
+                    let (prefix, rest) = optic.split_at_lock();
+                    ($optic(prefix, $($field)*), rest)
+                }
+            }
+        )*
+    };
+}
+
+/// the names of the field and variant optics in a path, known at compile time
+#[derive(Copy, Clone, Debug)]
+pub struct Segments {
+    len: usize,
+    names: [&'static str; Segments::MAX_DEPTH],
+    variants: [bool; Segments::MAX_DEPTH],
+}
+
+impl Segments {
+    pub const MAX_DEPTH: usize = 32;
+
+    pub const EMPTY: Segments = Segments {
+        len: 0,
+        names: [""; Segments::MAX_DEPTH],
+        variants: [false; Segments::MAX_DEPTH],
+    };
+
+    /// the path starting with the field `name` and going on with `rest`
+    pub const fn cons(name: &'static str, rest: Segments) -> Segments {
+        rest.push_front(name, false)
+    }
+
+    /// the path starting with the variant `name` and going on with `rest`
+    pub const fn cons_variant(name: &'static str, rest: Segments) -> Segments {
+        rest.push_front(name, true)
+    }
+
+    const fn push_front(self, name: &'static str, variant: bool) -> Segments {
+        assert!(self.len < Segments::MAX_DEPTH, "the path is too deep");
+        let mut names = [""; Segments::MAX_DEPTH];
+        let mut variants = [false; Segments::MAX_DEPTH];
+        names[0] = name;
+        variants[0] = variant;
+        let mut i = 0;
+        while i < self.len {
+            names[i + 1] = self.names[i];
+            variants[i + 1] = self.variants[i];
+            i += 1;
+        }
+        Segments {
+            len: self.len + 1,
+            names,
+            variants,
+        }
+    }
+
+    /// the names of the optics in order, with whether they are variants
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, bool)> + '_ {
+        let len = self.len;
+        self.names[..len]
+            .iter()
+            .copied()
+            .zip(self.variants[..len].iter().copied())
+    }
+
+    /// whether the paths part at different fields before going into any variant of an enum
+    /// in common, so neither is a prefix of the other.
+    /// The sibling variants overlap, since matching one reads the discriminant,
+    /// which may live in the payload of another with the niche layouts
+    pub const fn is_disjoint(&self, other: &Segments) -> bool {
+        let mut i = 0;
+        while i < self.len && i < other.len {
+            if self.variants[i] || other.variants[i] {
+                return false;
+            }
+            if !str_eq(self.names[i], other.names[i]) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// whether every two of the paths are disjoint
+    pub const fn are_disjoint(paths: &[Segments]) -> bool {
+        let mut i = 0;
+        while i < paths.len() {
+            let mut j = i + 1;
+            while j < paths.len() {
+                if !paths[i].is_disjoint(&paths[j]) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// the optics made of the field and variant optics only, whose path is known at compile time
+pub trait StaticPath {
+    const PATH: Segments;
+}
+
+/// to split the optics after its longest prefix of the field and variant optics
+/// from the rest optics going on from there
+pub trait SplitStaticPath {
+    type Prefix: StaticPath;
+    type Rest;
+
+    fn split_static_path(self) -> (Self::Prefix, Self::Rest);
+}
+
+macro_rules! impl_static_path {
+    ($($cons:ident: $($optic:ident),*;)*) => {
+        $($(
+            impl<Optic: crate::StaticPath> crate::StaticPath for $optic<Optic> {
+                const PATH: crate::Segments = crate::Segments::$cons(stringify!($optic), Optic::PATH);
+            }
+
+            impl<Optic: crate::SplitStaticPath> crate::SplitStaticPath for $optic<Optic> {
+                type Prefix = $optic<Optic::Prefix>;
+                type Rest = Optic::Rest;
+
+                #[inline]
+                fn split_static_path(self) -> (Self::Prefix, Self::Rest) {
+                    let (prefix, rest) = self.0.split_static_path();
+                    ($optic(prefix), rest)
+                }
+            }
+        )*)*
+    };
+}
+
+// the other optics end the prefix
+macro_rules! impl_split_static_path {
+    ($($optic:ident<$($param:ident,)*>),* $(,)?) => {
+        $(
+            impl<$($param,)* Optic> crate::SplitStaticPath for $optic<$($param,)* Optic> {
+                type Prefix = __;
+                type Rest = Self;
+
+                #[inline]
+                fn split_static_path(self) -> (__, Self) {
+                    (__, self)
+                }
+            }
+        )*
+    };
+}
+
 pub mod generated {
     /// the basic optic, behave as the identity functor
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -64,7 +255,22 @@ pub mod generated {
     #[allow(non_camel_case_types)]
     pub struct _both<Optic>(pub Optic);
 
-    /// to traverse items of collections, implemented `Traversal`
+    /// to traverse every branch of a tuple of optics with the same image, implemented `Traversal`,
+    /// written as `a.{b, c}._0` in `optics!`, which is `a._branch((b._0, c._0))`
+    ///
+    /// `TraversalMut` requires every branch to start with disjoint paths of the field and variant optics,
+    /// and goes on from there by the rest optics of the branch, as the optics after the braces.
+    /// Moving the foci out clones the source for every branch but the last one.
+    ///
+    /// ```ignore
+    /// x.for_each_mut(optics!(config.{primary, fallback}.hosts._mapped), |host| host.push_str(":80"));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _branch<Branches>(pub Branches);
+
+    /// to traverse items of collections, implemented `Traversal`,
+    /// and `TraversalOwned` for the sets and `BinaryHeap`, which rebuild themselves from the rewritten items
     ///
     /// ```ignore
     /// assert_eq!(vec![vec![1,2], vec![3,4]].traverse(_mapped._mapped), vec![1, 2, 3, 4])
@@ -110,7 +316,35 @@ pub mod generated {
     #[allow(non_camel_case_types)]
     pub struct _mut<Optic>(pub Optic);
 
-    /// behave as `xs[index]`
+    /// to visit the data in `Rc`, `Arc` or `Cow` with clone-on-write, implemented `Lens`
+    ///
+    /// A `Cow<str>` or `Cow<[T]>` is written as the `str` or `[T]` borrowed from its owned form.
+    ///
+    /// ```ignore
+    /// let x = Rc::new((1, 2));
+    /// let mut y = x.clone();
+    /// *y.view_mut(optics!(_cow._0)) += 1; // clone the shared data by `Rc::make_mut`
+    /// assert_eq!((x.0, y.0), (1, 2));
+    /// let mut s = Cow::Borrowed("cow");
+    /// s.view_mut(optics!(_cow)).make_ascii_uppercase(); // `&mut str` in the owned `String`
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _cow<Optic>(pub Optic);
+
+    /// to visit the target of any `Deref`, implemented `LensRef`, and `LensMut` for `DerefMut`
+    ///
+    /// ```ignore
+    /// assert_eq!(String::from("foo").view_ref(optics!(_deref)), "foo");
+    /// let mut x = vec![1, 2, 3];
+    /// x.view_mut(optics!(_deref)).reverse(); // visit the `[i32]`
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _deref<Optic>(pub Optic);
+
+    /// behave as `xs[index]`, which panics out of bounds,
+    /// except that previewing has nothing there as `xs.get(index)`
     ///
     /// ```ignore
     /// assert_eq!(vec![1,2,3].view_ref(optics!([1])), &2);
@@ -120,6 +354,329 @@ pub mod generated {
     #[allow(non_camel_case_types)]
     pub struct _ix<I, Optic>(pub Optic, pub I);
 
+    /// behave as `xs.get(index)`, implemented `Prism` which previews `None` out of bounds
+    ///
+    /// ```ignore
+    /// assert_eq!(vec![1,2,3].preview_ref(optics!([?1])), Some(&2));
+    /// assert_eq!(vec![1,2,3].preview_ref(optics!([?3])), None);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _at<I, Optic>(pub Optic, pub I);
+
+    /// to visit the value of a key in maps, inserting `Default::default()` when the key is absent
+    /// on writing, implemented `Lens`
+    ///
+    /// Reading never inserts: an absent key has no focus for `preview_ref` and `preview`,
+    /// `view` gives the default, and `view_ref` panics on it like `map[key]`,
+    /// since there's nowhere to borrow the default from.
+    ///
+    /// ```ignore
+    /// let mut map = HashMap::new();
+    /// *map.view_mut(optics!(_entry("a")._or_default)) += 1;
+    /// assert_eq!(map["a"], Some(1));
+    /// assert_eq!(map.preview(optics!(_entry("b"))), None);
+    /// assert_eq!(map.view(optics!(_entry("b"))), None);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _entry<K, Optic>(pub Optic, pub K);
+
+    /// to visit the data in `Option`, inserting the supplied value when it's `None` on writing,
+    /// implemented `Lens`, reading `None` like `_entry` reads an absent key
+    ///
+    /// ```ignore
+    /// let mut x = None;
+    /// assert_eq!(x.view(optics!(_non(1))), 1);
+    /// *x.view_mut(optics!(_non(1))) += 1;
+    /// assert_eq!(x, Some(2));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _non<V, Optic>(pub Optic, pub V);
+
+    /// to visit the data in `Option`, inserting `Default::default()` when it's `None` on writing,
+    /// implemented `Lens`, reading `None` like `_entry` reads an absent key
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _or_default<Optic>(pub Optic);
+
+    /// to try the first optic and fall back to the second when it misses,
+    /// implemented `Prism` and `Review` if both are prisms with the same image,
+    /// which ends the path, as `optics!` rejects the optics after it
+    ///
+    /// ```ignore
+    /// let x: Either<i32, i32> = Right(1);
+    /// assert_eq!(x.preview(optics!(_or(optics!(Left), optics!(Right)))), Some(1));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _or<First, Second, Optic>(pub Optic, pub First, pub Second);
+
+    /// to visit the keys of maps, implemented `TraversalRef` only
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _keys<Optic>(pub Optic);
+
+    /// to visit the entries of maps as the pairs `(&key, &value)`, or `(&key, &mut value)`
+    /// when mutating, implemented `Traversal`
+    ///
+    /// The pair is a temporary, so the optics go on to the key by `_0` or to the value by `_1`,
+    /// optionally after `_filtered` whose predicate sees the pair as `&(&K, &V)`.
+    ///
+    /// ```ignore
+    /// map.for_each_mut(
+    ///     optics!(_entries._filtered(|(k, _): &(&&str, &i32)| k.starts_with("http_"))._1),
+    ///     |n| *n += 1,
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _entries<Optic>(pub Optic);
+
+    /// to visit the values of `BTreeMap` whose keys are in the range, implemented `Traversal`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _range<R, Optic>(pub Optic, pub R);
+
+    /// to visit the data satisfying the predicate, implemented `Traversal`,
+    /// and `Prism` if the rest optics is
+    ///
+    /// ```ignore
+    /// let x = vec![Some(1), None, Some(5)];
+    /// assert_eq!(x.traverse(optics!(_mapped.Some._filtered(|i: &i32| *i > 3))), vec![5]);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _filtered<P, Optic>(pub Optic, pub P);
+
+    /// to visit the first element satisfying the predicate in a sequence, implemented `Prism`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _find<P, Optic>(pub Optic, pub P);
+
+    /// to match the value equal to the given one,
+    /// implemented `Prism` and `Review` with the image `()`
+    ///
+    /// ```ignore
+    /// assert!(x.has(optics!(_mapped._only(3))));
+    /// assert_eq!(Review::review(optics!(_only(3)), ()), 3);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _only<V, Optic>(pub Optic, pub V);
+
+    /// to match the value satisfying the predicate,
+    /// implemented `Prism` and `Review` with the image `()` which reviews the given value
+    ///
+    /// ```ignore
+    /// assert!(0.0.has(optics!(_nearly(0.0, |x: &f64| x.abs() < 1e-9))));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _nearly<V, P, Optic>(pub Optic, pub V, pub P);
+
+    /// to visit the first `n` foci of the rest optics at most, implemented `Traversal`
+    ///
+    /// `_taking`, `_dropping`, `_backwards` and `_element` modify the foci of the rest optics
+    /// when they lead the path, or of the optics before them when they end it;
+    /// `optics!` rejects them in the middle of the path or in a branch.
+    ///
+    /// ```ignore
+    /// assert_eq!(x.traverse(optics!(_taking(2)._mapped._0)), vec![1, 2]);
+    /// assert_eq!(x.traverse(optics!(_mapped._0._taking(2))), vec![1, 2]);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _taking<Optic>(pub Optic, pub usize);
+
+    /// to visit the foci of the rest optics except the first `n`, implemented `Traversal`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _dropping<Optic>(pub Optic, pub usize);
+
+    /// to visit the foci of the rest optics in reverse order, implemented `Traversal`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _backwards<Optic>(pub Optic);
+
+    /// to visit the `i`th focus of the rest optics, implemented `Prism`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _element<Optic>(pub Optic, pub usize);
+
+    /// to visit every line of a `str` or `String` as `str`, implemented `TraversalMut`,
+    /// and to move every line of a `String` out as `String`, implemented `TraversalOwned`
+    ///
+    /// ```ignore
+    /// let log = String::from("INFO start\nWARN slow");
+    /// assert_eq!(log.traverse_ref(optics!(_lines._ix(..4))), vec!["INFO", "WARN"]);
+    /// let log = log.over_owned(optics!(_lines), |line| line.replace("slow", "fast"));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _lines<Optic>(pub Optic);
+
+    /// to visit every whitespace-separated word of a `str` or `String` as `str`,
+    /// implemented `TraversalMut` and `TraversalOwned`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _words<Optic>(pub Optic);
+
+    /// to visit every character of a `str` or `String` as the `str` holding it,
+    /// implemented `TraversalMut` and `TraversalOwned`
+    ///
+    /// A `str` stores the characters encoded in UTF-8 rather than as `char`,
+    /// so there is no `char` to borrow, and the foci are the `str` slices of one character each.
+    ///
+    /// ```ignore
+    /// let mut s = String::from("lens");
+    /// s.for_each_mut(optics!(_char_strs), |c| c.make_ascii_uppercase());
+    /// assert_eq!(s, "LENS");
+    /// let s = s.over_owned(optics!(_char_strs), |c| c.repeat(2));
+    /// assert_eq!(s, "LLEENNSS");
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _char_strs<Optic>(pub Optic);
+
+    /// to move every character of a `String` out as `char`,
+    /// implemented `TraversalOwned`
+    ///
+    /// The `char`s are decoded out of the UTF-8 bytes,
+    /// so `over_owned` rebuilds the `String` from the rewritten ones;
+    /// use `_char_strs` to borrow the characters in place.
+    ///
+    /// ```ignore
+    /// let s = String::from("lens");
+    /// assert_eq!(s.clone().traverse_owned(optics!(_chars)), vec!['l', 'e', 'n', 's']);
+    /// let s = s.over_owned(optics!(_chars), |c| c.to_ascii_uppercase());
+    /// assert_eq!(s, "LENS");
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _chars<Optic>(pub Optic);
+
+    /// to visit the rest of a `str` or `String` after the prefix,
+    /// implemented `PrismMut`, `TraversalOwned` and `Review`
+    ///
+    /// ```ignore
+    /// assert_eq!("foobar".preview_ref(optics!(_prefixed("foo"))), Some("bar"));
+    /// let s: String = Review::review(optics!(_prefixed("foo")), "bar");
+    /// assert_eq!(s, "foobar");
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _prefixed<P, Optic>(pub Optic, pub P);
+
+    /// to visit the rest of a `str` or `String` before the suffix,
+    /// implemented `PrismMut`, `TraversalOwned` and `Review`
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _suffixed<P, Optic>(pub Optic, pub P);
+
+    /// to convert a struct to/from the tuple of all its fields, implemented `Iso`
+    ///
+    /// ```ignore
+    /// assert_eq!(Foo { a: 1, b: 2 }.to(optics!(_tuple)), (1, 2));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _tuple<Optic>(pub Optic);
+
+    /// to convert a single-field struct to/from its field, implemented `Lens` and `Iso`
+    ///
+    /// ```ignore
+    /// assert_eq!(Meters(1.0).to(optics!(_inner)), 1.0);
+    /// assert_eq!(Celsius { degrees: 1.0 }.view_ref(optics!(_inner)), &1.0);
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _inner<Optic>(pub Optic);
+
+    /// to invert an `Iso`, implemented `Iso`
+    ///
+    /// ```ignore
+    /// let meters: Meters = 1.0.to(optics!(_re(optics!(_inner))));
+    /// assert_eq!(meters, Meters(1.0));
+    /// ```
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[allow(non_camel_case_types)]
+    pub struct _re<Is, Optic>(pub Optic, pub Is);
+
+    impl crate::SplitAtLock for __ {
+        type Prefix = __;
+        type Rest = crate::NoLock;
+
+        #[inline]
+        fn split_at_lock(self) -> (__, crate::NoLock) {
+            (__, crate::NoLock)
+        }
+    }
+
+    // the branches go on without any lock optic
+    impl<Branches> crate::SplitAtLock for _branch<Branches> {
+        type Prefix = _branch<Branches>;
+        type Rest = crate::NoLock;
+
+        #[inline]
+        fn split_at_lock(self) -> (Self::Prefix, crate::NoLock) {
+            (self, crate::NoLock)
+        }
+    }
+
+    impl_split_at_lock! {
+        Ok<>(), Err<>(), Some<>(), None<>(),
+        _0<>(), _1<>(), _2<>(), _3<>(), _4<>(), _5<>(), _6<>(), _7<>(), _8<>(),
+        _9<>(), _10<>(), _11<>(), _12<>(), _13<>(), _14<>(), _15<>(), _16<>(),
+        _both<>(), _mapped<>(), _box<>(), _ref<>(), _mut<>(), _cow<>(), _deref<>(),
+        _ix<I,>(index), _at<I,>(index), _entry<K,>(key), _non<V,>(default), _or_default<>(),
+        _or<First, Second,>(first, second),
+        _keys<>(), _entries<>(), _range<R,>(range), _filtered<P,>(pred), _find<P,>(pred),
+        _only<V,>(value), _nearly<V, P,>(value, pred), _taking<>(n), _dropping<>(n),
+        _backwards<>(), _element<>(n), _lines<>(), _words<>(), _char_strs<>(), _chars<>(),
+        _prefixed<P,>(prefix),
+        _suffixed<P,>(suffix), _tuple<>(), _inner<>(), _re<Is,>(iso),
+    }
+
+    impl_split_static_path! {
+        _both<>, _mapped<>, _box<>, _ref<>, _mut<>, _cow<>, _deref<>,
+        _ix<I,>, _at<I,>, _entry<K,>, _non<V,>, _or_default<>, _or<First, Second,>,
+        _keys<>, _entries<>, _range<R,>, _filtered<P,>, _find<P,>,
+        _only<V,>, _nearly<V, P,>, _taking<>, _dropping<>,
+        _backwards<>, _element<>, _lines<>, _words<>, _char_strs<>, _chars<>, _prefixed<P,>,
+        _suffixed<P,>, _tuple<>, _inner<>, _re<Is,>,
+    }
+
+    impl<Branches> crate::SplitStaticPath for _branch<Branches> {
+        type Prefix = __;
+        type Rest = Self;
+
+        #[inline]
+        fn split_static_path(self) -> (__, Self) {
+            (__, self)
+        }
+    }
+
+    impl crate::SplitStaticPath for __ {
+        type Prefix = __;
+        type Rest = __;
+
+        #[inline]
+        fn split_static_path(self) -> (__, __) {
+            (__, __)
+        }
+    }
+
+    impl crate::StaticPath for __ {
+        const PATH: crate::Segments = crate::Segments::EMPTY;
+    }
+
+    impl_static_path! {
+        cons_variant: Ok, Err, Some, None;
+        cons: _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, _10, _11, _12, _13, _14, _15, _16;
+    }
+
     include!(concat!(env!("OUT_DIR"), "/optics.rs"));
 }
 
@@ -74,6 +74,62 @@ mod tests {
         #[optic] all_optics: String,
     }
 
+    // derive iso
+    #[derive(Copy, Clone, Debug, PartialEq, Lens, Iso)]
+    struct Meters(#[optic] f64);
+
+    #[derive(Copy, Clone, Debug, PartialEq, Lens, Iso)]
+    struct Celsius {
+        #[optic]
+        degrees: f64,
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Iso)]
+    struct Kelvin {
+        kelvin: f64,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Lens, Iso)]
+    struct Run {
+        #[optic]
+        dist: Meters,
+        #[optic]
+        temp: Celsius,
+    }
+
+    // derive path
+    #[derive(Debug, Lens)]
+    #[optic(path)]
+    struct Server {
+        #[optic]
+        port: u16,
+        #[optic]
+        tls: Option<Tls>,
+        #[optic]
+        workers: Vec<Worker<Mode>>,
+        secret: String,
+    }
+
+    #[derive(Debug, Lens)]
+    #[optic(path)]
+    struct Tls {
+        #[optic]
+        cert_path: String,
+    }
+
+    #[derive(Debug, Lens)]
+    #[optic(path)]
+    struct Worker<M>(#[optic] String, #[optic] M);
+
+    #[derive(Debug, Prism)]
+    #[optic(path)]
+    enum Mode {
+        #[optic]
+        Fixed(u32),
+        #[optic]
+        Auto,
+    }
+
     // T may have i32
     fn may_have_i32<T: PrismRef<Pm, i32>, Pm>(t: &T, pm: Pm) -> Option<i32> {
         t.preview_ref(pm).map(|x| *x)
@@ -162,6 +218,301 @@ mod tests {
         assert_eq!(*foo1, "foo1");
     }
 
+    fn test_cow() {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let x = Rc::new((1, vec![2, 3]));
+        let mut y = x.clone();
+        *y.view_mut(optics!(_cow._0)) += 1;
+        y.for_each_mut(optics!(_cow._1._mapped), |i| *i *= 2);
+        assert_eq!(*x, (1, vec![2, 3]));
+        assert_eq!(*y, (2, vec![4, 6]));
+        assert_eq!(x.view_ref(optics!(_cow._0)), &1);
+
+        let z = Arc::new(Some(1));
+        let w = z.clone().over(optics!(_cow.Some), |i| i + 1);
+        assert_eq!((*z, *w), (Some(1), Some(2)));
+
+        let v = [1, 2, 3];
+        let mut c = Cow::Borrowed(&v[..]);
+        assert_eq!(c.traverse_ref(optics!(_cow._mapped)), vec![&1, &2, &3]);
+        c.for_each_mut(optics!(_cow._mapped), |i| *i += 1);
+        assert_eq!((&v[..], &c[..]), (&[1, 2, 3][..], &[2, 3, 4][..]));
+        c.view_mut(optics!(_cow)).reverse();
+        assert_eq!(c[..], [4, 3, 2]);
+
+        let mut s = Cow::Borrowed("cow");
+        s.view_mut(optics!(_cow)).make_ascii_uppercase();
+        assert_eq!(s, "COW");
+    }
+
+    fn test_deref() {
+        use std::mem::ManuallyDrop;
+        use std::ops::{Deref, DerefMut};
+        use std::pin::Pin;
+
+        struct Wrapper<T>(T);
+
+        impl<T> Deref for Wrapper<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for Wrapper<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        let s = String::from("foo");
+        assert_eq!(s.view_ref(optics!(_deref)), "foo");
+
+        let mut x = (vec![3, 2, 1], Wrapper((1, 2)));
+        x.view_mut(optics!(_0._deref)).sort();
+        *x.view_mut(optics!(_1._deref._1)) += 1;
+        assert_eq!(x.traverse_ref(optics!(_0._deref._mapped)), vec![&1, &2, &3]);
+        assert_eq!(x.view_ref(optics!(_1._deref)), &(1, 3));
+
+        let mut y = (ManuallyDrop::new(Some(1)), Pin::new(Box::new((2, 3))));
+        *y.preview_mut(optics!(_0._deref.Some)).unwrap() += 1;
+        *y.view_mut(optics!(_1._deref._0)) *= 10;
+        assert_eq!(y.preview_ref(optics!(_0._deref.Some)), Some(&2));
+        assert_eq!(y.view_ref(optics!(_1._deref._0)), &20);
+    }
+
+    fn test_text() {
+        let mut log = String::from("INFO start up\nWARN slow  disk\nINFO done");
+        assert_eq!(
+            log.traverse_ref(optics!(_lines._ix(..4))),
+            vec!["INFO", "WARN", "INFO"]
+        );
+        assert_eq!(log.length_of(optics!(_words)), 8);
+        assert_eq!(
+            log.traverse_ref(optics!(_lines._prefixed("WARN "))),
+            vec!["slow  disk"]
+        );
+        log.for_each_mut(optics!(_lines._prefixed("INFO ")._words), |w| {
+            w.make_ascii_uppercase()
+        });
+        assert_eq!(log, "INFO START UP\nWARN slow  disk\nINFO DONE");
+
+        let mut file = String::from("main.rs");
+        assert_eq!(file.preview_ref(optics!(_suffixed(".rs"))), Some("main"));
+        assert_eq!(file.preview_ref(optics!(_suffixed(".toml"))), None);
+        let first = optics!(_suffixed(".rs")._char_strs._taking(1));
+        file.for_each_mut(first, |c| c.make_ascii_uppercase());
+        assert_eq!(file, "Main.rs");
+        assert_eq!(
+            "héllo".traverse_ref(optics!(_char_strs)),
+            vec!["h", "é", "l", "l", "o"]
+        );
+
+        let path = log.itraverse_ref(optics!(_lines._words))[4].0.to_string();
+        assert_eq!(path, "[1][1]");
+
+        let name: String = Review::review(optics!(_prefixed("lib")._suffixed(".rs")), "lens");
+        assert_eq!(name, "liblens.rs");
+
+        let log = log.over_owned(optics!(_lines._prefixed("WARN ")), |s| s.replace("  ", " "));
+        assert_eq!(log, "INFO START UP\nWARN slow disk\nINFO DONE");
+        let words = log.clone().traverse_owned(optics!(_lines._words));
+        assert_eq!(words[4..6], ["slow".to_string(), "disk".to_string()]);
+        let log = log.over_owned(optics!(_words._prefixed("ST")), |s| s.to_lowercase());
+        assert_eq!(log, "INFO STart UP\nWARN slow disk\nINFO DONE");
+        let hello = String::from("héllo");
+        let dots = hello.over_owned(optics!(_char_strs), |c| c + ".");
+        assert_eq!(dots, "h.é.l.l.o.");
+        let chars = dots.clone().traverse_owned(optics!(_chars));
+        assert_eq!(chars[..3], ['h', '.', 'é']);
+        let upper = dots.over_owned(optics!(_chars), |c| c.to_ascii_uppercase());
+        assert_eq!(upper, "H.é.L.L.O.");
+    }
+
+    fn test_scoped() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use std::sync::{Arc, Mutex, RwLock};
+
+        let mut x = Foo {
+            a: Rc::new(RefCell::new((1, 2))),
+            b: vec![Arc::new(Mutex::new(Some(3))), Arc::new(Mutex::new(None))],
+        };
+        assert_eq!(x.with_view_ref(optics!(a._borrow._1), |i| *i), 2);
+        x.with_view_mut(optics!(a._borrow._0), |i| *i += 10);
+        assert_eq!(*x.a.borrow(), (11, 2));
+
+        x.with_each_mut(optics!(b._mapped._lock.Some), |i| *i += 1);
+        let mut foci = vec![];
+        x.with_each_ref(optics!(b._mapped._lock.Some), |i| foci.push(*i));
+        assert_eq!(foci, vec![4]);
+
+        let mut y = RwLock::new(vec![RefCell::new((1, 'a')), RefCell::new((2, 'b'))]);
+        y.with_each_mut(optics!(_write._mapped._borrow._0), |i| *i *= 3);
+        let mut sum = 0;
+        y.with_each_ref(optics!(_read._mapped._borrow._0), |i| sum += *i);
+        assert_eq!(sum, 9);
+
+        assert_eq!((1, 2).with_view_ref(optics!(_1), |i| *i), 2);
+
+        let shared = &x.a;
+        Rc::clone(shared).with_view_mut(optics!(_borrow._1), |i| *i += 1);
+        shared.borrow_mut().0 += 1;
+        assert_eq!(shared.with_view_ref(optics!(_borrow), |p| *p), (12, 3));
+
+        type Shared = Rc<RefCell<(i32, i32)>>;
+        let mut z = (x.a.clone(), 0);
+        let a = optics!(
+            lens_fn(|z: &(Shared, i32)| &z.0, |z: &mut (Shared, i32)| &mut z.0)
+                ._borrow
+                ._0
+        );
+        z.with_view_mut(a, |i| *i = 0);
+        let a = optics!(_to(|z: &(Shared, i32)| &z.0)._borrow._0);
+        assert_eq!(z.with_view_ref(a, |i| *i), 0);
+
+        let mut cells = vec![RefCell::new(1), RefCell::new(2)];
+        let first = optics!(
+            prism_fn(
+                |v: &Vec<RefCell<i32>>| v.first(),
+                |v: &mut Vec<RefCell<i32>>| v.first_mut(),
+                |c| vec![c]
+            )
+            ._borrow
+        );
+        cells.with_each_mut(first, |i| *i += 10);
+        assert_eq!(cells, vec![RefCell::new(11), RefCell::new(2)]);
+    }
+
+    fn test_parsed() {
+        let mut x = Foo {
+            a: String::from("8080"),
+            b: vec![b"lens".to_vec(), vec![0xff]],
+        };
+        x.with_each_mut(optics!(a._parsed::<u16>), |port| *port += 1);
+        assert_eq!(x.a, "8081");
+        let mut ports = vec![];
+        x.with_each_ref(optics!(a._parsed::<u16>), |port| ports.push(*port));
+        assert_eq!(ports, vec![8081]);
+
+        x.with_each_mut(optics!(b._mapped._utf8), |s: &mut String| s.push_str("-rs"));
+        assert_eq!(x.b, vec![b"lens-rs".to_vec(), vec![0xff]]);
+
+        let mut y = vec![
+            String::from("1.5"),
+            String::from("NaN?"),
+            String::from("(2)"),
+        ];
+        y.with_each_mut(optics!(_mapped._parsed::<f64>), |f| *f *= 2.0);
+        assert_eq!(y, vec!["3", "NaN?", "(2)"]);
+
+        let port: String = Review::review(optics!(_parsed::<u16>), 80u16);
+        assert_eq!(port, "80");
+        let z: Option<Vec<u8>> = Review::review(optics!(Some._utf8), "rs".to_string());
+        assert_eq!(z, Some(b"rs".to_vec()));
+
+        assert_eq!(x.a.preview_owned(optics!(_parsed::<u16>)), Some(8081));
+        assert_eq!("443".preview_owned(optics!(_parsed::<u16>)), Some(443));
+        assert_eq!("ssh".preview_owned(optics!(_parsed::<u16>)), None);
+        let ports = String::from("80\n443\nssh");
+        let ports = ports.over_owned(optics!(_lines._parsed::<u16>), |i| i + 8000);
+        assert_eq!(ports, "8080\n8443\nssh");
+        let numbers = String::from("1 x 2").traverse_owned(optics!(_words._parsed::<i32>));
+        assert_eq!(numbers, vec![1, 2]);
+        let upper = b"lens"
+            .to_vec()
+            .over_owned(optics!(_utf8), |s| s.to_uppercase());
+        assert_eq!(upper, b"LENS");
+        assert_eq!(
+            vec![0xff].over_owned(optics!(_utf8), |_| String::new()),
+            vec![0xff]
+        );
+
+        let pair = (String::from("8080"), 1).over_owned(optics!(_0._parsed::<u16>), |i| i + 1);
+        assert_eq!(pair, ("8081".to_string(), 1));
+        let y = y.over_owned(optics!(_mapped._parsed::<f64>), |f| f + 1.0);
+        assert_eq!(y, vec!["4", "NaN?", "(2)"]);
+        let some = Some(String::from("7"));
+        assert_eq!(some.preview_owned(optics!(Some._parsed::<u8>)), Some(7));
+        let ssh = Foo {
+            a: String::from("22"),
+            b: 0,
+        };
+        assert_eq!(ssh.preview_owned(optics!(a._parsed::<u16>)), Some(22));
+    }
+
+    fn test_split() {
+        let mut x = Foo {
+            a: (1, 'a'),
+            b: Tuple(vec![2], Some(3)),
+        };
+        let (a0, b0, a1) = x.view_mut_many((optics!(a._0), optics!(b._0), optics!(a._1)));
+        *a0 += 1;
+        b0.push(*a0);
+        *a1 = 'b';
+        assert_eq!(x.a, (2, 'b'));
+        assert_eq!(x.b.0, vec![2, 2]);
+
+        let (b1, a0) = x.preview_mut_many((optics!(b._1.Some), optics!(a._0)));
+        std::mem::swap(b1.unwrap(), a0.unwrap());
+        assert_eq!((x.a.0, x.b.1), (3, Some(2)));
+
+        let mut y = (Some((1, 2)), Left::<i32, i32>(3), Ok::<bool, ()>(true));
+        let (p, q, r) = y.preview_mut_many((optics!(_0.Some), optics!(_1.Right), optics!(_2.Ok)));
+        assert_eq!((p, q), (Some(&mut (1, 2)), None));
+        *r.unwrap() = false;
+        assert_eq!(y.2, Ok(false));
+    }
+
+    fn test_branch() {
+        let mut x = Foo {
+            a: (1, 2),
+            b: (Some(3), 4),
+        };
+        x.for_each_mut(optics!(a.{_0, _1}), |n| *n *= 10);
+        assert_eq!(x.a, (10, 20));
+
+        fn both<T: TraversalRef<Optics![a.{_0, _1}], i32>>(t: &T) -> Vec<&i32> {
+            t.traverse_ref(optics!(a.{_0, _1}))
+        }
+        assert_eq!(both(&x), vec![&10, &20]);
+        assert_eq!(x.sum_of::<i32>(optics!({a._1, b.{_0.Some, _1}})), 27);
+
+        let paths = x.itraverse_mut(optics!({a._0, b._0.Some}));
+        let paths = paths
+            .into_iter()
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["a._0", "b._0.Some"]);
+
+        let x: Foo<(i32, i32), (Option<i32>, i32)> = x.over(optics!({b._0.Some, a._1}), |n| -n);
+        assert_eq!((x.a, x.b), ((10, -20), (Some(-3), 4)));
+
+        let y = Foo {
+            a: vec![1],
+            b: vec![2, 3],
+        };
+        assert_eq!(y.traverse_ref(optics!({a, b}._mapped)), vec![&1, &2, &3]);
+        assert_eq!(y.traverse(optics!({b, a})), vec![vec![2, 3], vec![1]]);
+
+        let mut z = Foo {
+            a: Tuple(vec![1], 'a'),
+            b: Tuple(vec![2, 3], 'b'),
+        };
+        z.for_each_mut(optics!({a, b}._0._mapped), |n| *n *= 10);
+        assert_eq!((&z.a.0, &z.b.0), (&vec![10], &vec![20, 30]));
+        let paths = z.itraverse_mut(optics!({b._0._mapped, a._0._mapped}));
+        let paths = paths
+            .into_iter()
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["b._0[0]", "b._0[1]", "a._0[0]"]);
+    }
+
     fn test_index() {
         let mut x = (1, vec![2, 3]);
         *x.view_mut(optics!(_1.[0])) *= 2;
@@ -193,13 +544,940 @@ mod tests {
         assert_eq!(may_has_c((1, 2, 3)), None);
     }
 
+    fn test_or() {
+        fn c_or_b<T>(t: T) -> Option<i32>
+        where
+            T: Prism<Optics![_or(Optics![c], Optics![b])], i32>,
+        {
+            t.preview(optics!(_or(optics!(c), optics!(b))))
+        }
+
+        let foo = Foo { a: (), b: 1 };
+        let bar = Bar {
+            a: "this is Bar".to_string(),
+            c: 2,
+        };
+        assert_eq!(c_or_b(foo), Some(1));
+        assert_eq!(c_or_b(bar), Some(2));
+        assert_eq!(c_or_b((1, 2, 3)), None);
+
+        let mut x: Either<i32, i32> = Right(3);
+        let either = optics!(_or(optics!(Left), optics!(Right)));
+        *x.preview_mut(either).unwrap() += 1;
+        assert_eq!(x.preview_ref(either), Some(&4));
+        let x = Left::<i32, i32>(1).over(either, |i| -i);
+        assert_eq!(x.preview_ref(optics!(Left)), Some(&-1));
+        let x = <Either<i32, i32>>::review(either, 5);
+        assert_eq!(x.preview(optics!(Left)), Some(5));
+
+        let mut y = (Some(1), 2);
+        let first = optics!(_or(optics!(_0.Some), optics!(_1)));
+        y.for_each_mut(first, |i| *i *= 10);
+        assert_eq!(y, (Some(10), 2));
+        y.0 = None;
+        assert_eq!(y.itraverse_ref(first)[0].0.to_string(), "_1");
+
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let tried = AtomicUsize::new(0);
+        let big = |i: &i32| {
+            tried.fetch_add(1, Ordering::Relaxed);
+            *i > 5
+        };
+        let big_or_last = optics!(_or(optics!(_0._filtered(big)), optics!(_1)));
+        let mut z = (10, 0);
+        z.for_each_mut(big_or_last, |i| *i += 1);
+        assert_eq!(z, (11, 0));
+        assert_eq!(tried.swap(0, Ordering::Relaxed), 1);
+        let z = z.over(big_or_last, |i| i * 2);
+        assert_eq!(z, (22, 0));
+        assert_eq!(tried.swap(0, Ordering::Relaxed), 1);
+        let mut w = (vec![10], 0);
+        let first_big = optics!(_or(optics!(_0._find(big)), optics!(_1)));
+        *w.preview_mut(first_big).unwrap() += 1;
+        assert_eq!(w, (vec![11], 0));
+        assert_eq!(tried.load(Ordering::Relaxed), 1);
+    }
+
+    fn test_miss() {
+        use std::collections::HashMap;
+
+        let mut x = (1, Ok::<_, ()>((vec![Some(("a", 2)), None], 4)));
+        assert_eq!(x.try_preview_ref(optics!(_1.Ok._0.[0].Some._1)), Ok(&2));
+        let miss = x
+            .try_preview_ref(optics!(_1.Ok._0.[2].Some._1))
+            .unwrap_err();
+        assert_eq!(
+            (miss.depth(), miss.to_string()),
+            (3, "index 2 out of bounds at `_1.Ok._0`".into())
+        );
+        let checked = x
+            .try_preview_ref(optics!(_1.Ok._0.[?2].Some._1))
+            .map(|_| ());
+        let miss = x
+            .try_preview_mut(optics!(_1.Ok._0.[2].Some._1))
+            .unwrap_err();
+        assert_eq!(Err(miss), checked);
+        assert_eq!(x.preview_ref(optics!(_1.Ok._0.[2..5])), None);
+        let miss = x
+            .try_preview_mut(optics!(_1.Ok._0.[?1].Some._1))
+            .unwrap_err();
+        assert_eq!(
+            miss.to_string(),
+            "expected `Some`, found other variant at `_1.Ok._0[1]`"
+        );
+        *x.try_preview_mut(optics!(_1.Ok._0.[0].Some._1)).unwrap() += 1;
+        assert_eq!(x.try_preview(optics!(_1.Ok._0.[?0].Some._1)), Ok(3));
+
+        let miss = (1, Err::<i32, _>(0))
+            .try_preview(optics!(_1.Ok))
+            .unwrap_err();
+        assert_eq!(
+            miss.to_string(),
+            "expected `Ok`, found other variant at `_1`"
+        );
+
+        let foo = Foo {
+            a: Left::<i32, i32>(1),
+            b: (),
+        };
+        let miss = foo.try_preview_ref(optics!(a.Right)).unwrap_err();
+        assert_eq!(miss.reason, path::Miss::Variant { expected: "Right" });
+        assert_eq!(
+            miss.to_string(),
+            "expected `Right`, found other variant at `a`"
+        );
+        let miss = foo.try_preview(optics!(c)).map(|c: i32| c).unwrap_err();
+        assert_eq!(
+            (miss.depth(), miss.to_string()),
+            (0, "`c` has no focus".into())
+        );
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), vec![1]);
+        let miss = map.try_preview_ref(optics!([?"b"])).unwrap_err();
+        assert_eq!(miss.to_string(), "no entry for key \"b\"");
+
+        let mut tree = std::collections::BTreeMap::<char, i32>::new();
+        *tree.try_preview_mut(optics!(_entry('b'))).unwrap() += 1;
+        assert_eq!(tree[&'b'], 1);
+        let mut y = (0, None::<i32>);
+        *y.try_preview_mut(optics!(_1._non(2))).unwrap() += 1;
+        assert_eq!(y.1, Some(3));
+
+        let y = (0, vec![(1, None), (2, Some(3))]);
+        let miss = y
+            .try_preview_ref(optics!(_1._find(|x: &(i32, Option<i32>)| x.0 == 1)._1.Some))
+            .unwrap_err();
+        assert_eq!(
+            miss.to_string(),
+            "expected `Some`, found other variant at `_1[0]._1`"
+        );
+        let miss = y
+            .try_preview_ref(optics!(_1._find(|x: &(i32, Option<i32>)| x.0 > 2)))
+            .unwrap_err();
+        assert_eq!(
+            (miss.depth(), miss.to_string()),
+            (1, "`_find` has no focus at `_1`".into())
+        );
+        let miss = (1, Some(2))
+            .try_preview_ref(optics!(_1._filtered(|x: &Option<i32>| x.is_none())))
+            .unwrap_err();
+        assert_eq!(miss.to_string(), "`_filtered` has no focus at `_1`");
+        let miss = (1, 2).try_preview_ref(optics!(_0._only(2))).unwrap_err();
+        assert_eq!(miss.reason, path::Miss::NoFocus { optic: "_only" });
+    }
+
+    fn test_over() {
+        let x = (
+            1,
+            Left::<_, i32>(Tuple(vec![Some(Foo { a: 1, b: 2 }), None], 3)),
+        )
+            .over(optics!(_1.Left._0._mapped.Some.a), |a| a * 10)
+            .set(optics!(_1.Left._1), 0)
+            .set(optics!(_1.Right), 4)
+            .over(optics!(_0), |n| n + 1);
+        assert_eq!(x.0, 2);
+        assert_eq!(x.preview_ref(optics!(_1.Left._1)), Some(&0));
+        assert_eq!(x.traverse(optics!(_1.Left._0._mapped.Some.a)), vec![10]);
+
+        let y = vec![(1, 2), (3, 4)].over(optics!(_mapped._both), |i| i * 2);
+        assert_eq!(y, vec![(2, 4), (6, 8)]);
+
+        let z: Nat = Review::review(optics!(S._box.Z), ());
+        let z = z.set(optics!(S._box), Z);
+        assert_eq!(z, S(Box::new(Z)));
+
+        let w = (1, std::sync::Mutex::new(2)).put(optics!(_1), std::sync::Mutex::new(3));
+        assert_eq!(*w.1.lock().unwrap(), 3);
+        assert_eq!(
+            Bar {
+                a: "bar".to_string(),
+                c: 0
+            }
+            .set(optics!(b), 1)
+            .c,
+            0
+        );
+    }
+
+    fn test_for_each() {
+        let mut x = (1, vec![vec![Some((2, 3)), None], vec![Some((4, 5))]]);
+        x.for_each_mut(optics!(_1._mapped._mapped.Some._0), |i| *i *= 10);
+
+        let mut sum = 0;
+        x.for_each_ref(optics!(_1._mapped._mapped.Some._both), |i| sum += *i);
+        assert_eq!(sum, 68);
+
+        let mut firsts = vec![];
+        x.for_each_move(optics!(_1._mapped._mapped.Some._0), |i| firsts.push(i));
+        assert_eq!(firsts, vec![20, 40]);
+    }
+
+    fn test_fold() {
+        let x = (
+            1,
+            Ok::<_, ()>((vec![Some(("a", 2)), None, Some(("b", 3))], 4)),
+        );
+        assert!(x.has(optics!(_1.Ok._0._mapped.Some)));
+        assert!(!x.has(optics!(_1.Err)));
+        assert_eq!(x.first_of(optics!(_1.Ok._0._mapped.Some._1)), Some(&2));
+        assert_eq!(x.length_of(optics!(_1.Ok._0._mapped.Some)), 2);
+        assert_eq!(x.sum_of::<i32>(optics!(_1.Ok._0._mapped.Some._1)), 5);
+        assert_eq!(x.max_of(optics!(_1.Ok._0._mapped.Some._0)), Some(&"b"));
+        assert_eq!(
+            x.max_of_by(optics!(_1.Ok._0._mapped.Some._1), |a, b| b.cmp(a)),
+            Some(&2)
+        );
+        assert!(x.all_of(optics!(_1.Ok._0._mapped.Some._1), |i| *i > 1));
+        assert!(!x.any_of(optics!(_1.Ok._0._mapped.Some._1), |i| *i > 3));
+
+        let mut visited = 0;
+        let found = x.find_of(optics!(_1.Ok._0._mapped), |o| {
+            visited += 1;
+            o.is_none()
+        });
+        assert_eq!(found, Some(&None));
+        assert_eq!(visited, 2);
+    }
+
+    fn test_iso() {
+        assert_eq!(Meters(1.5).to(optics!(_inner)), 1.5);
+        let meters: Meters = Iso::from_image(optics!(_inner), 1.5);
+        assert_eq!(meters, Meters(1.5));
+        let meters: Meters = 1.5.to(optics!(_re(optics!(_inner))));
+        assert_eq!(meters, Meters(1.5));
+        assert_eq!(Celsius { degrees: 20.0 }.to(optics!(_inner)), 20.0);
+
+        let mut kelvin = Kelvin { kelvin: 1.0 };
+        *kelvin.view_mut(optics!(_inner)) += 1.0;
+        assert_eq!(kelvin.view_ref(optics!(_inner)), &2.0);
+        assert_eq!(
+            kelvin.over(optics!(_inner), |k| k * 2.0),
+            Kelvin { kelvin: 4.0 }
+        );
+        let kelvin: Kelvin = Review::review(optics!(_inner), 3.0);
+        assert_eq!(kelvin.view(optics!(_inner)), 3.0);
+
+        let run = Run {
+            dist: Meters(42.0),
+            temp: Celsius { degrees: 20.0 },
+        };
+        assert_eq!(run.view_ref(optics!(dist._0)), &42.0);
+        assert_eq!(
+            run.clone().to(optics!(_tuple)),
+            (Meters(42.0), Celsius { degrees: 20.0 })
+        );
+        let back: Run = Iso::from_image(optics!(_tuple), (Meters(1.0), Celsius { degrees: 2.0 }));
+        assert_eq!(back.dist, Meters(1.0));
+        assert_eq!(Meters(3.0).to(optics!(_tuple._0)), 3.0);
+        assert_eq!(Box::new(Meters(3.0)).to(optics!(_box._inner)), 3.0);
+        assert_eq!(run.over(optics!(dist._0), |d| d * 2.0).dist, Meters(84.0));
+    }
+
+    fn test_dyn() {
+        use std::rc::Rc;
+
+        let mut x: (Foo<i32, i32>, Result<(i32, String), ()>) =
+            (Foo { a: 1, b: 2 }, Ok((3, "c".to_string())));
+
+        let columns: Vec<DynLens<_, i32>> = vec![
+            DynLens::new(optics!(_0.a)),
+            DynLens::new(optics!(_0.b)),
+            DynLens::new(optics!(_0)).then(optics!(b)),
+        ];
+        let row: Vec<i32> = columns.iter().map(|c| *x.view_ref(c)).collect();
+        assert_eq!(row, vec![1, 2, 2]);
+        *x.view_mut(&columns[0]) += 10;
+        assert_eq!(x.0.a, 11);
+
+        let pm = DynPrism::new(optics!(_1.Ok)).then(optics!(_0));
+        assert_eq!(x.preview_ref(pm.clone()), Some(&3));
+        *x.preview_mut(pm.clone()).unwrap() = 30;
+        assert!(Fold::has(&x, pm));
+        assert_eq!(x.preview_ref(DynPrism::new(optics!(_1.Err))), None);
+
+        let mut v = vec![Some((1, 2)), None, Some((3, 4))];
+        let tr = DynTraversal::new(optics!(_mapped.Some)).then(optics!(_both));
+        assert_eq!(v.sum_of::<i32>(tr.clone()), 10);
+        assert_eq!(v.find_of(tr.clone(), |i| *i > 2), Some(&3));
+        v.for_each_mut(tr, |i| *i *= 2);
+        assert_eq!(v, vec![Some((2, 4)), None, Some((6, 8))]);
+
+        let shared = Rc::new((1, Some((2, 3))));
+        let second = DynLensRef::<Rc<(i32, Option<(i32, i32)>)>, _>::new(optics!(_ref._1));
+        assert_eq!(shared.view_ref(&second), &Some((2, 3)));
+        let pm = DynPrismRef::new(optics!(_ref._1.Some)).then(optics!(_1));
+        assert_eq!(shared.preview_ref(pm), Some(&3));
+        let tr = DynTraversalRef::<Rc<Vec<i32>>, _>::new(optics!(_ref._mapped));
+        assert_eq!(Rc::new(vec![1, 2]).sum_of::<i32>(tr), 3);
+
+        let rv: DynReview<Option<Result<i32, ()>>, i32> = DynReview::new(optics!(Some.Ok));
+        let built: Option<Result<i32, ()>> = Review::review(rv, 1);
+        assert_eq!(built, Some(Ok(1)));
+    }
+
+    fn test_path() {
+        let server = Server {
+            port: 443,
+            tls: Some(Tls {
+                cert_path: "cert.pem".to_string(),
+            }),
+            workers: vec![
+                Worker("a".to_string(), Mode::Auto),
+                Worker("b".to_string(), Mode::Fixed(4)),
+            ],
+            secret: "hunter2".to_string(),
+        };
+        assert_eq!(path::get_as::<u16>(&server, "port"), Ok(&443));
+        assert_eq!(
+            path::get_as::<String>(&server, "tls.Some.cert_path").map(|s| s.as_str()),
+            Ok("cert.pem")
+        );
+        assert_eq!(
+            path::get_ref(&server, "workers[1]._1.Fixed")
+                .unwrap()
+                .downcast_ref::<u32>(),
+            Some(&4)
+        );
+        assert_eq!(
+            format!("{:?}", path::get_debug(&server, "workers[0]._1").unwrap()),
+            "Auto"
+        );
+        assert!(path::get_debug(&server, "workers[0]._1.Fixed").is_none());
+
+        assert_eq!(
+            path::get_as::<u32>(&server, "workers[0]._1.Fixed"),
+            Err(path::PathError::Unresolved {
+                segment: "Fixed".to_string(),
+                offset: 14,
+            })
+        );
+        assert_eq!(
+            path::get_as::<String>(&server, "secret").unwrap_err(),
+            path::PathError::Unresolved {
+                segment: "secret".to_string(),
+                offset: 0,
+            }
+        );
+        assert_eq!(
+            path::get_as::<String>(&server, "workers[2]").unwrap_err(),
+            path::PathError::Unresolved {
+                segment: "[2]".to_string(),
+                offset: 7,
+            }
+        );
+        assert_eq!(server.secret, "hunter2");
+        assert!(matches!(
+            path::get_as::<u32>(&server, "port"),
+            Err(path::PathError::Mismatch { .. })
+        ));
+        assert!(matches!(
+            path::get_as::<u16>(&server, "tls..cert_path"),
+            Err(path::PathError::Syntax { .. })
+        ));
+        assert!(matches!(
+            path::get_as::<u16>(&server, "workers[0"),
+            Err(path::PathError::Syntax { .. })
+        ));
+
+        let x = (1, Some(vec![2, 3, 4]), Ok::<_, ()>(Box::new(5)));
+        assert_eq!(path::get_as::<i32>(&x, "_1.Some[2]"), Ok(&4));
+        assert_eq!(path::get_as::<Box<i32>>(&x, "_2.Ok"), Ok(&Box::new(5)));
+    }
+
+    fn test_itraverse() {
+        use lens_rs::path::{OpticPath, PathKey, Segment};
+        use std::collections::{BTreeMap, HashMap};
+
+        #[derive(Eq, Hash, PartialEq)]
+        struct Token(u64);
+        impl PathKey for Token {}
+
+        let mut x = (
+            1,
+            Ok::<_, ()>((vec![Some((1i32, 'a')), None, Some((-3, 'c'))], 0)),
+        );
+        let invalid = x
+            .itraverse_ref(optics!(_1.Ok._0._mapped.Some._0))
+            .into_iter()
+            .filter(|(_, i)| **i < 0)
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, vec!["_1.Ok._0[2].Some._0"]);
+
+        let paths = x.itraverse_mut(optics!(_1.Ok._0._mapped.Some._0));
+        assert_eq!(
+            paths[1].0.segments(),
+            &[
+                Segment::Field("_1"),
+                Segment::Variant("Ok"),
+                Segment::Field("_0"),
+                Segment::Index(2),
+                Segment::Variant("Some"),
+                Segment::Field("_0"),
+            ]
+        );
+        for (_, i) in paths {
+            *i = i.abs();
+        }
+        assert_eq!(x.1.unwrap().0[2], Some((3, 'c')));
+
+        let run = Run {
+            dist: Meters(1.0),
+            temp: Celsius { degrees: 2.0 },
+        };
+        assert_eq!(
+            run.itraverse_ref(optics!(temp.degrees))[0].0.to_string(),
+            "temp.degrees"
+        );
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), vec![(1, 2)]);
+        let paths = map.itraverse_ref(optics!(["a"]._mapped._both));
+        assert_eq!(paths[1].0.to_string(), "[\"a\"][0]._1");
+        assert_eq!(
+            path::get_as::<i32>(&map, &paths[1].0.to_string()),
+            Ok(paths[1].1)
+        );
+
+        let mut arr = [Box::new(Some(1)), Box::new(None)];
+        let mut prefix = OpticPath::new();
+        prefix.push(Segment::Field("arr"));
+        arr.ifor_each_mut(optics!([0]._box.Some), &mut prefix, |path, i| {
+            assert_eq!(path.to_string(), "arr[0].Some");
+            *i += 1;
+        });
+        assert_eq!(prefix.segments(), &[Segment::Field("arr")]);
+        assert_eq!(*arr[0], Some(2));
+
+        let mut tokens = HashMap::new();
+        tokens.insert(Token(7), 1);
+        assert_eq!(tokens.view_ref(optics!([&Token(7)])), &1);
+        assert_eq!(
+            tokens.itraverse_ref(optics!(_mapped))[0].0.to_string(),
+            "[_]"
+        );
+
+        // only the indexed optics need a `PathKey`
+        #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+        struct Opaque(u64);
+        let mut opaque = HashMap::new();
+        opaque.insert(Opaque(1), 1);
+        opaque.for_each_mut(optics!(_mapped), |i| *i += 1);
+        *opaque.view_mut(optics!(_entry(Opaque(2)))) += 5;
+        assert_eq!(opaque.view_ref(optics!([&Opaque(1)])), &2);
+        assert_eq!(opaque.preview_ref(optics!([&Opaque(2)])), Some(&5));
+        let opaque = opaque.into_iter().collect::<BTreeMap<_, _>>();
+        assert_eq!(opaque.traverse_ref(optics!(_range(Opaque(2)..))), vec![&5]);
+    }
+
+    fn test_at() {
+        use std::collections::{BTreeMap, HashMap, VecDeque};
+
+        let mut v = vec![(1, 2), (3, 4)];
+        assert_eq!(v.preview_ref(optics!([?1]._0)), Some(&3));
+        assert_eq!(v.preview_ref(optics!([?2]._0)), None);
+        assert_eq!(v.preview_ref(optics!([?1..])), Some(&[(3, 4)][..]));
+        assert_eq!(v.preview_ref(optics!([?3..])), None);
+        *v.preview_mut(optics!([?0]._1)).unwrap() += 10;
+        v.for_each_mut(optics!([?5]._1), |_| unreachable!());
+        assert_eq!(v.clone().preview(optics!([?0]._1)), Some(12));
+        assert_eq!(v.clone().preview(optics!([?9]._1)), None);
+        assert_eq!(
+            v.clone().over(optics!([?1]._0), |i| i * 10),
+            vec![(1, 12), (30, 4)]
+        );
+        assert_eq!(v.clone().over(optics!([?2]._0), |i| i * 10), v);
+        assert_eq!(
+            v.clone().over(optics!([?0]._0), |i| -i),
+            vec![(-1, 12), (3, 4)]
+        );
+
+        let mut deque = v.iter().copied().collect::<VecDeque<_>>();
+        *deque.preview_mut(optics!([?1]._1)).unwrap() = 0;
+        let negated = deque.clone().over(optics!([?0]._0), |i| -i);
+        assert_eq!(negated, VecDeque::from(vec![(-1, 12), (3, 0)]));
+        assert_eq!(deque.preview(optics!([?1])), Some((3, 0)));
+
+        let arr = [1, 2, 3];
+        assert_eq!(arr.preview_ref(optics!([?3])), None);
+        assert_eq!(arr.over(optics!([?1]), |i| -i), [1, -2, 3]);
+        assert_eq!(arr[..].preview_ref(optics!([?..2])), Some(&[1, 2][..]));
+
+        let mut s = "héllo".to_string();
+        assert_eq!(s.preview_ref(optics!([?3..])), Some("llo"));
+        assert_eq!(s.preview_ref(optics!([?..2])), None);
+        s.preview_mut(optics!([?3..]))
+            .unwrap()
+            .make_ascii_uppercase();
+        assert_eq!(s, "héLLO");
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), Some(1));
+        *map.preview_mut(optics!([?"a"].Some)).unwrap() += 1;
+        assert_eq!(map.preview_ref(optics!([?"b"].Some)), None);
+        assert_eq!(map.clone().preview(optics!([?"a"].Some)), Some(2));
+        let map = map.over(optics!([?"a"].Some), |i| i * 10);
+        assert_eq!(map["a"], Some(20));
+
+        let mut tree = BTreeMap::new();
+        tree.insert(1, "x");
+        assert!(tree.preview_mut(optics!([?&1])).is_some());
+        assert!(tree.preview_mut(optics!([?&2])).is_none());
+
+        let paths = v.itraverse_ref(optics!([?1]._0));
+        assert_eq!(paths[0].0.to_string(), "[1]._0");
+
+        fn probe<T: PrismRef<Optics![[?usize]], i32>>(t: &T) -> Option<&i32> {
+            t.preview_ref(optics!([?4]))
+        }
+        assert_eq!(probe(&vec![1]), None);
+    }
+
+    fn test_entry() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut config: HashMap<String, BTreeMap<u16, Option<(String, u32)>>> = HashMap::new();
+        assert_eq!(
+            config.preview_ref(optics!(_entry("web".to_string())._entry(80)._or_default._1)),
+            None
+        );
+        *config
+            .preview_mut(optics!(_entry("web".to_string())._entry(80)._or_default._1))
+            .unwrap() += 4;
+        *config.view_mut(optics!(_entry("web".to_string())._entry(80)._or_default._1)) += 4;
+        config.for_each_mut(
+            optics!(_entry("db".to_string())._entry(5432)._or_default._0),
+            |s| s.push_str("postgres"),
+        );
+        assert_eq!(config["web"][&80], Some((String::new(), 8)));
+        assert_eq!(config["db"][&5432], Some(("postgres".to_string(), 0)));
+
+        let paths = config.itraverse_ref(optics!(
+            _entry("db".to_string())._entry(5432)._or_default._0
+        ));
+        assert_eq!(paths[0].0.to_string(), "[\"db\"][5432].Some._0");
+
+        let mut x: Option<i32> = None;
+        assert_eq!(x.preview_ref(optics!(_non(1))), None);
+        *x.preview_mut(optics!(_non(1))).unwrap() += 1;
+        assert_eq!(x, Some(2));
+        *x.preview_mut(optics!(_non(10))).unwrap() += 1;
+        assert_eq!(x, Some(3));
+        assert_eq!(x.view(optics!(_non(10))), 3);
+        assert_eq!(None.preview(optics!(_non(7))), None::<i32>);
+        assert_eq!(None.view(optics!(_non(5))), 5);
+        assert_eq!(None::<(u8, i32)>.view(optics!(_or_default._1)), 0);
+        assert_eq!(None.over(optics!(_or_default), |i: i32| i + 1), Some(1));
+
+        let tree: BTreeMap<char, i32> = BTreeMap::new().over(optics!(_entry('a')), |i| i - 1);
+        assert_eq!(tree[&'a'], -1);
+        assert_eq!(tree.preview_ref(optics!(_entry('b'))), None);
+        assert_eq!(tree.clone().preview(optics!(_entry('b'))), None);
+        assert_eq!(tree.clone().view(optics!(_entry('b'))), 0);
+        assert_eq!(
+            tree.clone().traverse(optics!(_entry('b'))),
+            Vec::<i32>::new()
+        );
+        assert_eq!(tree.view_ref(optics!(_entry('a'))), &-1);
+        assert_eq!(tree.view(optics!(_entry('a'))), -1);
+    }
+
+    fn test_mapped() {
+        use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
+
+        let mut arr = [(1, 'a'), (2, 'b')];
+        arr.for_each_mut(optics!(_mapped._0), |i| *i += 1);
+        assert_eq!(arr[..].traverse_ref(optics!(_mapped._0)), vec![&2, &3]);
+        assert_eq!(
+            arr.over(optics!(_mapped._1), |c| c.to_ascii_uppercase()),
+            [(2, 'A'), (3, 'B')]
+        );
+        assert_eq!(arr.traverse(optics!(_mapped._1)), vec!['a', 'b']);
+
+        let boxed: Box<[Option<i32>]> = vec![Some(1), None, Some(3)].into_boxed_slice();
+        assert_eq!(boxed.length_of(optics!(_mapped.Some)), 2);
+        let boxed = boxed.over(optics!(_mapped.Some), |i| i * 10);
+        assert_eq!(&*boxed, &[Some(10), None, Some(30)]);
+
+        let mut opt = Some(vec![1, 2]);
+        opt.for_each_mut(optics!(_mapped._mapped), |i| *i *= 2);
+        assert_eq!(opt.clone().traverse(optics!(_mapped._mapped)), vec![2, 4]);
+        assert_eq!(None::<(i32,)>.over(optics!(_mapped._0), |i| i + 1), None);
+
+        let mut map = HashMap::new();
+        map.insert("a", (1, 2));
+        map.insert("b", (3, 4));
+        map.for_each_mut(optics!(_mapped._1), |i| *i = 0);
+        assert_eq!(map.sum_of::<i32>(optics!(_mapped._both)), 4);
+        let map = map.over(optics!(_mapped._0), |i| -i);
+        assert_eq!(map["b"], (-3, 0));
+
+        let tree = vec![(2, "x"), (1, "y")]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let paths = tree.itraverse_ref(optics!(_mapped));
+        assert_eq!(paths[1].0.to_string(), "[2]");
+        assert_eq!(tree.traverse(optics!(_mapped)), vec!["y", "x"]);
+
+        let set = vec![(1, 'a'), (2, 'b')].into_iter().collect::<HashSet<_>>();
+        assert_eq!(set.sum_of::<i32>(optics!(_mapped._0)), 3);
+        let set = set.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(set.traverse_ref(optics!(_mapped._1)), vec![&'a', &'b']);
+        let set = set.over_owned(optics!(_mapped._0), |i| 3 - i);
+        assert_eq!(set.traverse_ref(optics!(_mapped._1)), vec![&'b', &'a']);
+        let heap = set.into_iter().collect::<BinaryHeap<_>>();
+        assert_eq!(heap.max_of(optics!(_mapped._0)), Some(&2));
+        let heap = heap.over_owned(optics!(_mapped._0), |i| i * 10);
+        assert_eq!(heap.peek(), Some(&(20, 'a')));
+        let set = vec![1, 2, 3].into_iter().collect::<HashSet<_>>();
+        let set = set.over_owned(optics!(_mapped), |i| i / 2);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.traverse_owned(optics!(_mapped)).len(), 2);
+    }
+
+    fn test_map() {
+        use std::collections::{BTreeMap, HashMap};
+        use std::ops::Bound;
+
+        let mut counters = HashMap::new();
+        counters.insert("http_get", 1);
+        counters.insert("http_post", 2);
+        counters.insert("db_query", 3);
+        let http = |(k, _): &(&&str, &i32)| k.starts_with("http_");
+        counters.for_each_mut(optics!(_entries._filtered(http)._1), |n| *n += 1);
+        assert_eq!(counters["http_get"], 2);
+        assert_eq!(counters["http_post"], 3);
+        assert_eq!(counters["db_query"], 3);
+        assert_eq!(counters.length_of(optics!(_keys)), 3);
+        assert!(counters.any_of(optics!(_keys), |k| k.starts_with("db_")));
+
+        let tree = (1..=5)
+            .map(|i| (i, (i * 10, ())))
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(tree.traverse_ref(optics!(_keys)), vec![&1, &2, &3, &4, &5]);
+        assert_eq!(tree.traverse_ref(optics!(_range(2..4)._0)), vec![&20, &30]);
+        #[allow(clippy::reversed_empty_ranges)]
+        let inverted = 5..3;
+        assert!(tree.traverse_ref(optics!(_range(inverted)._0)).is_empty());
+        let empty = (Bound::Excluded(3), Bound::Excluded(3));
+        assert!(tree.itraverse_ref(optics!(_range(empty)._0)).is_empty());
+        let paths = tree.itraverse_ref(optics!(_range(4..)._0));
+        assert_eq!(paths[0].0.to_string(), "[4]._0");
+        let mut tree = tree.over(optics!(_range(..=2)._0), |i| -i);
+        #[allow(clippy::reversed_empty_ranges)]
+        tree.for_each_mut(optics!(_range(4..=1)._0), |i| *i = 0);
+        let large = |(_, v): &(&i32, &(i32, ()))| v.0 > 40;
+        tree.for_each_mut(optics!(_entries._filtered(large)._1._0), |i| *i = 0);
+        let upper = |(k, _): &(&i32, &(i32, ()))| **k > 3;
+        assert_eq!(
+            tree.traverse_ref(optics!(_entries._filtered(upper)._0)),
+            vec![&4, &5]
+        );
+        let paths = tree.itraverse_ref(optics!(_entries._1._0));
+        assert_eq!(paths[1].0.to_string(), "[2]._0");
+        assert_eq!(
+            tree.traverse(optics!(_range(..)._0)),
+            vec![-10, -20, 30, 40, 0]
+        );
+    }
+
+    fn test_predicate() {
+        let mut x = vec![
+            Some(Foo { a: "x", b: 1 }),
+            None,
+            Some(Foo { a: "y", b: 4 }),
+            Some(Foo { a: "z", b: 5 }),
+        ];
+        let big = |foo: &Foo<&str, i32>| foo.b > 3;
+        assert_eq!(
+            x.traverse_ref(optics!(_mapped.Some._filtered(big).a)),
+            vec![&"y", &"z"]
+        );
+        x.for_each_mut(optics!(_mapped.Some._filtered(big).b), |b| *b *= 10);
+        assert_eq!(x.sum_of::<i32>(optics!(_mapped.Some.b)), 91);
+        let paths = x.itraverse_ref(optics!(_mapped.Some._filtered(big).a));
+        assert_eq!(paths[0].0.to_string(), "[2].Some.a");
+
+        let first_big = |foo: &Option<Foo<&str, i32>>| foo.as_ref().is_some_and(big);
+        assert_eq!(x.preview_ref(optics!(_find(first_big).Some.a)), Some(&"y"));
+        *x.preview_mut(optics!(_find(first_big).Some.b)).unwrap() = 0;
+        assert_eq!(x.preview_ref(optics!(_find(first_big).Some.a)), Some(&"z"));
+        let x = x.over(optics!(_find(first_big).Some.b), |b| b + 1);
+        assert_eq!(x.traverse(optics!(_mapped.Some.b)), vec![1, 0, 51]);
+
+        let arr = [1, 2, 3, 4];
+        assert_eq!(arr.preview(optics!(_find(|i: &i32| i % 2 == 0))), Some(2));
+        assert_eq!(
+            arr.over(optics!(_find(|i: &i32| i % 2 == 0)), |i| -i),
+            [1, -2, 3, 4]
+        );
+        assert_eq!(arr[..].preview_ref(optics!(_find(|i: &i32| *i > 4))), None);
+
+        assert!(arr.has(optics!(_mapped._only(3))));
+        assert_eq!(arr.length_of(optics!(_mapped._only(5))), 0);
+        assert_eq!(Some(3).preview(optics!(Some._only(3))), Some(()));
+        let three: i32 = Review::review(optics!(_only(3)), ());
+        assert_eq!(three, 3);
+        let nested: Option<i32> = Review::review(optics!(Some._only(3)), ());
+        assert_eq!(nested, Some(3));
+
+        let near_zero = |x: &f64| x.abs() < 1e-9;
+        assert!(1e-12.has(optics!(_nearly(0.0, near_zero))));
+        assert_eq!(0.5.preview_ref(optics!(_nearly(0.0, near_zero))), None);
+        let zero: f64 = Review::review(optics!(_nearly(0.0, near_zero)), ());
+        assert_eq!(zero, 0.0);
+    }
+
+    fn test_fn() {
+        let mut x = (
+            1,
+            Bar {
+                a: "bar".to_string(),
+                c: 2,
+            },
+        );
+        let c = optics!(_1.lens_fn(|bar: &Bar| &bar.c, |bar: &mut Bar| &mut bar.c));
+        *x.view_mut(c) += 1;
+        assert_eq!(x.view_ref(c), &3);
+        let paths = x.itraverse_ref(c);
+        assert_eq!(paths[0].0.to_string(), "_1");
+
+        assert_eq!(
+            x.view_ref(optics!(_1.a._to(|s: &String| s.as_str()))),
+            "bar"
+        );
+        assert_eq!(
+            x.traverse_ref(optics!(_to(|x: &(i32, Bar)| &x.1).c)),
+            vec![&3]
+        );
+        let len = optics!(_1.a._to_value(|s: &String| s.len()));
+        assert_eq!(x.with_view_ref(len, |n| *n), 3);
+        let (bars, mut lens) = (vec![x.1.clone(), x.1], vec![]);
+        let len = optics!(_mapped.a._to_value(|s: &String| s.len()));
+        bars.with_each_ref(len, |n| lens.push(*n));
+        assert_eq!(lens, vec![3, 3]);
+
+        let first = optics!(prism_fn(
+            |v: &Vec<i32>| v.first(),
+            |v: &mut Vec<i32>| v.first_mut(),
+            |i| vec![i]
+        ));
+        let mut v = vec![1, 2];
+        *v.preview_mut(first).unwrap() = 0;
+        assert_eq!(v.preview_ref(first), Some(&0));
+        assert_eq!(vec![].preview_ref(first), None);
+        let reviewed: Vec<i32> = Review::review(first, 5);
+        assert_eq!(reviewed, vec![5]);
+    }
+
+    fn test_modifier() {
+        let mut x = vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+        assert_eq!(x.traverse_ref(optics!(_taking(2)._mapped._0)), vec![&1, &2]);
+        assert_eq!(x.traverse_ref(optics!(_dropping(3)._mapped._1)), vec![&'d']);
+        assert_eq!(
+            x.traverse_ref(optics!(_backwards._mapped._0)),
+            vec![&4, &3, &2, &1]
+        );
+        assert_eq!(
+            x.first_of(optics!(_dropping(1)._taking(2)._mapped._1)),
+            Some(&'b')
+        );
+        assert_eq!(x.length_of(optics!(_taking(0)._mapped)), 0);
+        let last: Optics![_mapped._1._backwards._taking(1)] =
+            optics!(_mapped._1._backwards._taking(1));
+        assert_eq!(x.traverse_ref(last), vec![&'d']);
+
+        x.for_each_mut(optics!(_taking(2)._mapped._0), |i| *i *= 10);
+        x.for_each_mut(optics!(_element(3)._mapped._1), |c| *c = 'z');
+        assert_eq!(x.traverse_ref(optics!(_mapped._0)), vec![&10, &20, &3, &4]);
+        assert_eq!(x.preview_ref(optics!(_element(3)._mapped._1)), Some(&'z'));
+        assert_eq!(x.preview_ref(optics!(_element(4)._mapped._1)), None);
+        *x.preview_mut(optics!(_element(0)._mapped._0)).unwrap() = 0;
+
+        let paths = x.itraverse_ref(optics!(_backwards._dropping(2)._mapped._1));
+        assert_eq!(paths[0].0.to_string(), "[3]._1");
+        assert_eq!(paths[1].0.to_string(), "[2]._1");
+
+        let mut n = 0;
+        let numbered = x.clone().over(optics!(_backwards._mapped._0), |_| {
+            n += 1;
+            n
+        });
+        assert_eq!(numbered.traverse(optics!(_mapped._0)), vec![4, 3, 2, 1]);
+        let x = x.over(optics!(_dropping(2)._mapped._0), |i| -i);
+        assert_eq!(
+            x.clone().traverse(optics!(_backwards._mapped._0)),
+            vec![-4, -3, 20, 0]
+        );
+        assert_eq!(x.preview(optics!(_element(1)._mapped._1)), Some('b'));
+
+        #[derive(Debug, PartialEq)]
+        struct Token(u8);
+        let mut seen = vec![];
+        let tokens = vec![Token(1), Token(2), Token(3)];
+        let tokens = tokens.over(optics!(_mapped._backwards), |Token(t)| {
+            seen.push(t);
+            Token(t * 10)
+        });
+        assert_eq!(seen, vec![3, 2, 1]);
+        assert_eq!(
+            tokens.traverse(optics!(_backwards._mapped)),
+            vec![Token(30), Token(20), Token(10)]
+        );
+
+        let nested = vec![vec![(1, 'a'), (2, 'b')], vec![(3, 'c'), (4, 'd')]];
+        assert_eq!(
+            nested.traverse_ref(optics!(_mapped._mapped._0._taking(3))),
+            nested.traverse_ref(optics!(_taking(3)._mapped._mapped._0))
+        );
+        assert_eq!(
+            nested.traverse_ref(optics!(_mapped._mapped._0._backwards._taking(3))),
+            vec![&4, &3, &2]
+        );
+
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let visited = AtomicUsize::new(0);
+        let seen = |_: &(i32, char)| {
+            visited.fetch_add(1, Ordering::Relaxed);
+            true
+        };
+        let mut nested = nested;
+        let optics = optics!(_taking(2)._mapped._mapped._filtered(seen)._0);
+        nested.for_each_mut(optics, |i| *i = 0);
+        assert_eq!(visited.swap(0, Ordering::Relaxed), 2);
+        let optics = optics!(_element(0)._mapped._mapped._filtered(seen)._1);
+        nested.for_each_move(optics, drop);
+        assert_eq!(visited.load(Ordering::Relaxed), 1);
+    }
+
     #[test]
     fn it_works() {
         test_nested();
         test_row();
         test_ptr();
+        test_cow();
+        test_deref();
+        test_text();
+        test_scoped();
+        test_parsed();
+        test_split();
+        test_branch();
         test_index();
         test_absent();
+        test_or();
+        test_miss();
+        test_over();
+        test_for_each();
+        test_fold();
+        test_iso();
+        test_dyn();
+        test_path();
+        test_itraverse();
+        test_at();
+        test_entry();
+        test_mapped();
+        test_map();
+        test_predicate();
+        test_fn();
+        test_modifier();
+    }
+
+    #[test]
+    #[cfg(feature = "test_rayon")]
+    fn test_rayon() {
+        let mut x = (0..1000).map(|i| (i, vec![i; 3])).collect::<Vec<_>>();
+        x.par_for_each_mut(optics!(_mapped._1._mapped), |i| *i *= 2);
+        assert_eq!(x.sum_of::<i32>(optics!(_mapped._1._mapped)), 999 * 1000 * 3);
+
+        let foci = x[..10].par_traverse_mut(optics!(_mapped._0));
+        assert_eq!(foci.len(), 10);
+        foci.into_iter().for_each(|i| *i = -*i);
+        assert_eq!(
+            x.traverse_ref(optics!(_taking(3)._mapped._0)),
+            vec![&0, &-1, &-2]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "test_serde_json")]
+    fn test_serde_json() {
+        use serde_json::{json, Value};
+        let mut x = json!({ "name": "lens", "tags": ["optics", "rust"], "stars": 1, "ok": null });
+        assert_eq!(
+            x.preview_ref(optics!(_key("name")._String)),
+            Some(&"lens".to_string())
+        );
+        assert_eq!(x.preview_ref(optics!(_key("stars")._String)), None);
+        assert_eq!(
+            x.preview_ref(optics!(_key("tags")._idx(1)._String))
+                .unwrap(),
+            "rust"
+        );
+        assert_eq!(x.preview_ref(optics!(_key("ok")._Null)), Some(&()));
+
+        x.for_each_mut(optics!(_key("tags")._values._String), |s| s.push('!'));
+        assert_eq!(x["tags"], json!(["optics!", "rust!"]));
+        *x.preview_mut(optics!(_key("stars")._Number)).unwrap() = 2.into();
+        assert_eq!(x["stars"], json!(2));
+
+        let x = x.over(optics!(_key("name")._String), |s| s.to_uppercase());
+        assert_eq!(
+            x.clone().preview(optics!(_key("name")._String)).unwrap(),
+            "LENS"
+        );
+        assert_eq!(x.traverse(optics!(_values._Bool)), Vec::<bool>::new());
+
+        let y: Value = Review::review(optics!(_key("a")._idx(1)._Bool), true);
+        assert_eq!(y, json!({ "a": [null, true] }));
+    }
+
+    #[test]
+    #[cfg(feature = "test_codec")]
+    fn test_codec() {
+        let mut x = (String::from("6c656e73"), String::from("bGVucw=="));
+        x.with_each_mut(optics!(_0._hex._mapped), |b| *b = b.to_ascii_uppercase());
+        assert_eq!(x.0, "4c454e53");
+        x.with_each_mut(optics!(_1._base64._utf8), |s| s.push_str("-rs"));
+        assert_eq!(x.1, "bGVucy1ycw==");
+
+        let mut len = 0;
+        "zz".to_string()
+            .with_each_ref(optics!(_hex), |b: &Vec<u8>| len += b.len());
+        assert_eq!(len, 0);
+
+        let y: String = Review::review(optics!(_base64._utf8), "lens".to_string());
+        assert_eq!(y, "bGVucw==");
+        let z: String = Review::review(optics!(_hex), vec![0xca, 0xfe]);
+        assert_eq!(z, "cafe");
+
+        let name = x.1.preview_owned(optics!(_base64._utf8));
+        assert_eq!(name, Some("lens-rs".to_string()));
+        let z = z.over_owned(optics!(_hex), |mut b| {
+            b.reverse();
+            b
+        });
+        assert_eq!(z, "feca");
     }
 
     #[test]